@@ -0,0 +1,212 @@
+use std::fmt;
+use std::iter::Peekable;
+use std::str::Chars;
+
+use crate::Ratio;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ParseFormulaError {
+    token: String,
+}
+
+impl fmt::Display for ParseFormulaError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Invalid chemical formula '{}'", self.token)
+    }
+}
+
+impl std::error::Error for ParseFormulaError {}
+
+/// Computes the molar mass in grams per mole of a chemical formula such as
+/// `C6H12O6`, `Ca(OH)2` or the hydrate `CuSO4·5H2O`.
+///
+/// Groups may use round or square brackets, and the parts of a hydrate may
+/// be joined with `·`, `.` or `*`, each with an optional leading count.
+/// Standard atomic weights are used, abridged to five significant figures;
+/// elements without one count as their most stable isotope.
+pub fn molar_mass(formula: &str) -> Result<Ratio, ParseFormulaError> {
+    formula
+        .trim()
+        .split(['·', '.', '*'])
+        .try_fold(Ratio::ZERO, |total, part| total.checked_add(part_mass(part)?))
+        .ok_or_else(|| ParseFormulaError { token: formula.to_string() })
+}
+
+/// The mass of one part of a hydrate, such as `5H2O`.
+fn part_mass(part: &str) -> Option<Ratio> {
+    let mut chars = part.chars().peekable();
+    let coefficient = count(&mut chars)?;
+    let mass = sequence(&mut chars, None)?;
+    mass.checked_mul(Ratio::from(i64::from(coefficient)))
+}
+
+/// Sums elements and bracketed groups, each with its count, up to the
+/// bracket `close` or, if that is `None`, the end of the input.
+fn sequence(chars: &mut Peekable<Chars>, close: Option<char>) -> Option<Ratio> {
+    let mut total = Ratio::ZERO;
+    let mut empty = true;
+    loop {
+        let mass = match chars.next() {
+            Some('(') => sequence(chars, Some(')'))?,
+            Some('[') => sequence(chars, Some(']'))?,
+            Some(c) if Some(c) == close => break,
+            Some(c) if c.is_ascii_uppercase() => {
+                let mut symbol = c.to_string();
+                if let Some(c) = chars.next_if(char::is_ascii_lowercase) {
+                    symbol.push(c);
+                }
+                atomic_weight(&symbol)?
+            }
+            None if close.is_none() => break,
+            _ => return None,
+        };
+        let n = count(chars)?;
+        total = total.checked_add(mass.checked_mul(Ratio::from(i64::from(n)))?)?;
+        empty = false;
+    }
+
+    (!empty).then_some(total)
+}
+
+/// Reads a count such as the `2` of `H2`, which is 1 when left out. A count
+/// of zero is not valid.
+fn count(chars: &mut Peekable<Chars>) -> Option<u32> {
+    let mut digits = String::new();
+    while let Some(c) = chars.next_if(char::is_ascii_digit) {
+        digits.push(c);
+    }
+    if digits.is_empty() {
+        return Some(1);
+    }
+    digits.parse().ok().filter(|&n| n > 0)
+}
+
+/// The standard atomic weight of an element in grams per mole.
+fn atomic_weight(symbol: &str) -> Option<Ratio> {
+    ATOMIC_WEIGHTS
+        .iter()
+        .find(|(s, _)| *s == symbol)
+        .map(|(_, weight)| weight.parse().expect("atomic weights are valid decimals"))
+}
+
+/// IUPAC standard atomic weights, abridged, in order of atomic number.
+const ATOMIC_WEIGHTS: [(&str, &str); 118] = [
+    ("H", "1.008"),
+    ("He", "4.0026"),
+    ("Li", "6.94"),
+    ("Be", "9.0122"),
+    ("B", "10.81"),
+    ("C", "12.011"),
+    ("N", "14.007"),
+    ("O", "15.999"),
+    ("F", "18.998"),
+    ("Ne", "20.180"),
+    ("Na", "22.990"),
+    ("Mg", "24.305"),
+    ("Al", "26.982"),
+    ("Si", "28.085"),
+    ("P", "30.974"),
+    ("S", "32.06"),
+    ("Cl", "35.45"),
+    ("Ar", "39.95"),
+    ("K", "39.098"),
+    ("Ca", "40.078"),
+    ("Sc", "44.956"),
+    ("Ti", "47.867"),
+    ("V", "50.942"),
+    ("Cr", "51.996"),
+    ("Mn", "54.938"),
+    ("Fe", "55.845"),
+    ("Co", "58.933"),
+    ("Ni", "58.693"),
+    ("Cu", "63.546"),
+    ("Zn", "65.38"),
+    ("Ga", "69.723"),
+    ("Ge", "72.630"),
+    ("As", "74.922"),
+    ("Se", "78.971"),
+    ("Br", "79.904"),
+    ("Kr", "83.798"),
+    ("Rb", "85.468"),
+    ("Sr", "87.62"),
+    ("Y", "88.906"),
+    ("Zr", "91.224"),
+    ("Nb", "92.906"),
+    ("Mo", "95.95"),
+    ("Tc", "97"),
+    ("Ru", "101.07"),
+    ("Rh", "102.91"),
+    ("Pd", "106.42"),
+    ("Ag", "107.87"),
+    ("Cd", "112.41"),
+    ("In", "114.82"),
+    ("Sn", "118.71"),
+    ("Sb", "121.76"),
+    ("Te", "127.60"),
+    ("I", "126.90"),
+    ("Xe", "131.29"),
+    ("Cs", "132.91"),
+    ("Ba", "137.33"),
+    ("La", "138.91"),
+    ("Ce", "140.12"),
+    ("Pr", "140.91"),
+    ("Nd", "144.24"),
+    ("Pm", "145"),
+    ("Sm", "150.36"),
+    ("Eu", "151.96"),
+    ("Gd", "157.25"),
+    ("Tb", "158.93"),
+    ("Dy", "162.50"),
+    ("Ho", "164.93"),
+    ("Er", "167.26"),
+    ("Tm", "168.93"),
+    ("Yb", "173.05"),
+    ("Lu", "174.97"),
+    ("Hf", "178.49"),
+    ("Ta", "180.95"),
+    ("W", "183.84"),
+    ("Re", "186.21"),
+    ("Os", "190.23"),
+    ("Ir", "192.22"),
+    ("Pt", "195.08"),
+    ("Au", "196.97"),
+    ("Hg", "200.59"),
+    ("Tl", "204.38"),
+    ("Pb", "207.2"),
+    ("Bi", "208.98"),
+    ("Po", "209"),
+    ("At", "210"),
+    ("Rn", "222"),
+    ("Fr", "223"),
+    ("Ra", "226"),
+    ("Ac", "227"),
+    ("Th", "232.04"),
+    ("Pa", "231.04"),
+    ("U", "238.03"),
+    ("Np", "237"),
+    ("Pu", "244"),
+    ("Am", "243"),
+    ("Cm", "247"),
+    ("Bk", "247"),
+    ("Cf", "251"),
+    ("Es", "252"),
+    ("Fm", "257"),
+    ("Md", "258"),
+    ("No", "259"),
+    ("Lr", "262"),
+    ("Rf", "267"),
+    ("Db", "268"),
+    ("Sg", "269"),
+    ("Bh", "270"),
+    ("Hs", "269"),
+    ("Mt", "278"),
+    ("Ds", "281"),
+    ("Rg", "282"),
+    ("Cn", "285"),
+    ("Nh", "286"),
+    ("Fl", "289"),
+    ("Mc", "290"),
+    ("Lv", "293"),
+    ("Ts", "294"),
+    ("Og", "294"),
+];
@@ -0,0 +1,80 @@
+use std::fmt;
+
+use crate::duration::split_number;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ParseDmsError {
+    token: String,
+}
+
+impl fmt::Display for ParseDmsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Invalid angle '{}'", self.token)
+    }
+}
+
+impl std::error::Error for ParseDmsError {}
+
+/// Parses a degrees-minutes-seconds angle such as `12°30'15"` into degrees.
+///
+/// Any of the three parts may be left out, but they must come in order and
+/// minutes and seconds below a larger part must be under 60. Typographic
+/// primes (′ ″) are accepted, as is a trailing compass letter: "S" and "W"
+/// give a negative angle.
+pub fn parse_dms(s: &str) -> Result<f64, ParseDmsError> {
+    parse(s.trim()).ok_or_else(|| ParseDmsError { token: s.to_string() })
+}
+
+fn parse(token: &str) -> Option<f64> {
+    let (sign, token) = match token.strip_prefix('-') {
+        Some(rest) => (-1.0, rest),
+        None => (1.0, token),
+    };
+    let (sign, token) = match token.strip_suffix(['N', 'n', 'E', 'e']) {
+        Some(rest) => (sign, rest),
+        None => match token.strip_suffix(['S', 's', 'W', 'w']) {
+            // "-12°S" is contradictory
+            Some(rest) if sign > 0.0 => (-1.0, rest),
+            Some(_) => return None,
+            None => (sign, token),
+        },
+    };
+
+    let mut total = 0.0;
+    let mut last = 0;
+    let mut rest = token.trim();
+    while !rest.is_empty() {
+        let (value, after) = split_number(rest)?;
+        let after = after.trim_start();
+        let (rank, size, len) = designator(after)?;
+        if rank <= last || (last > 0 && value >= 60.0) {
+            return None;
+        }
+        total += value / size;
+        last = rank;
+        rest = after[len..].trim_start();
+    }
+
+    // A bare number is not a DMS angle
+    (last > 0).then_some(sign * total)
+}
+
+/// The rank and size in degrees of the designator `s` starts with, and its
+/// length.
+fn designator(s: &str) -> Option<(u8, f64, usize)> {
+    // "''" has to be tried before "'"
+    let designators = [
+        ("°", 1, 1.0),
+        ("º", 1, 1.0),
+        ("''", 3, 3600.0),
+        ("'", 2, 60.0),
+        ("′", 2, 60.0),
+        ("’", 2, 60.0),
+        ("\"", 3, 3600.0),
+        ("″", 3, 3600.0),
+    ];
+    designators
+        .into_iter()
+        .find(|(symbol, ..)| s.starts_with(symbol))
+        .map(|(symbol, rank, size)| (rank, size, symbol.len()))
+}
@@ -0,0 +1,144 @@
+use std::fmt;
+
+const MINUTE: f64 = 60.0;
+const HOUR: f64 = 3600.0;
+const DAY: f64 = 86_400.0;
+const WEEK: f64 = 604_800.0;
+const JULIAN_YEAR: f64 = 31_557_600.0;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ParseDurationError {
+    token: String,
+}
+
+impl fmt::Display for ParseDurationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Invalid duration '{}'", self.token)
+    }
+}
+
+impl std::error::Error for ParseDurationError {}
+
+/// Parses a duration into seconds.
+///
+/// Accepts compound durations such as "1h30m" or "2d 4h 30m" (where "m"
+/// means minutes) and ISO-8601 durations such as "PT1H30M" or "P1W2D".
+/// ISO months are rejected since they have no fixed length; years are
+/// Julian years of 365.25 days.
+pub fn parse_duration(s: &str) -> Result<f64, ParseDurationError> {
+    let token = s.trim();
+    let (sign, token) = match token.strip_prefix('-') {
+        Some(rest) => (-1.0, rest),
+        None => (1.0, token),
+    };
+    let seconds = match token.strip_prefix(['P', 'p']) {
+        Some(iso) => parse_iso(iso),
+        None => parse_compound(token),
+    };
+    seconds
+        .map(|seconds| sign * seconds)
+        .ok_or_else(|| ParseDurationError { token: s.to_string() })
+}
+
+/// Splits "12.5h..." into the number and whatever follows it.
+pub(crate) fn split_number(s: &str) -> Option<(f64, &str)> {
+    let len = s
+        .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == ','))
+        .unwrap_or(s.len());
+    if len == 0 {
+        return None;
+    }
+    let value = s[..len].replace(',', ".").parse().ok()?;
+    Some((value, &s[len..]))
+}
+
+fn component_seconds(unit: &str) -> Option<f64> {
+    match unit.to_lowercase().as_str() {
+        "y" | "yr" | "yrs" | "year" | "years" => Some(JULIAN_YEAR),
+        "w" | "wk" | "wks" | "week" | "weeks" => Some(WEEK),
+        "d" | "day" | "days" => Some(DAY),
+        "h" | "hr" | "hrs" | "hour" | "hours" => Some(HOUR),
+        "m" | "min" | "mins" | "minute" | "minutes" => Some(MINUTE),
+        "s" | "sec" | "secs" | "second" | "seconds" => Some(1.0),
+        "ms" => Some(1e-3),
+        "us" | "µs" | "μs" => Some(1e-6),
+        "ns" => Some(1e-9),
+        _ => None,
+    }
+}
+
+fn parse_compound(s: &str) -> Option<f64> {
+    let mut total = 0.0;
+    let mut rest = s;
+    while !rest.is_empty() {
+        let (value, after) = split_number(rest)?;
+        let after = after.trim_start();
+        let len = after.find(|c: char| !c.is_alphabetic()).unwrap_or(after.len());
+        total += value * component_seconds(&after[..len])?;
+        rest = after[len..].trim_start();
+    }
+
+    // An empty string or a bare number is not a duration
+    (!s.is_empty()).then_some(total)
+}
+
+fn parse_iso(s: &str) -> Option<f64> {
+    let (date, time) = match s.split_once(['T', 't']) {
+        Some((date, time)) => (date, Some(time)),
+        None => (s, None),
+    };
+    if date.is_empty() && time.is_none_or(str::is_empty) {
+        return None;
+    }
+
+    let mut total = 0.0;
+    for (mut part, is_time) in [(date, false), (time.unwrap_or_default(), true)] {
+        if is_time && time == Some("") {
+            return None;
+        }
+        while !part.is_empty() {
+            let (value, after) = split_number(part)?;
+            let designator = after.chars().next()?;
+            let size = match (is_time, designator.to_ascii_uppercase()) {
+                (false, 'Y') => JULIAN_YEAR,
+                (false, 'W') => WEEK,
+                (false, 'D') => DAY,
+                (true, 'H') => HOUR,
+                (true, 'M') => MINUTE,
+                (true, 'S') => 1.0,
+                _ => return None,
+            };
+            total += value * size;
+            part = &after[designator.len_utf8()..];
+        }
+    }
+
+    Some(total)
+}
+
+/// Formats seconds as a normalised compound duration such as "1d 2h 30m 15s",
+/// the form [`parse_duration`] reads back.
+pub fn format_duration(seconds: f64) -> String {
+    if !seconds.is_finite() {
+        return format!("{seconds}s");
+    }
+
+    // Work in whole nanoseconds so rounding never yields "60s"
+    let mut nanos = (seconds.abs() * 1e9).round() as u128;
+    let sign = if seconds < 0.0 && nanos > 0 { "-" } else { "" };
+    let mut parts = Vec::new();
+    for (size, symbol) in [(DAY, "d"), (HOUR, "h"), (MINUTE, "m")] {
+        let size = size as u128 * 1_000_000_000;
+        let count = nanos / size;
+        if count > 0 {
+            parts.push(format!("{count}{symbol}"));
+            nanos %= size;
+        }
+    }
+    if nanos > 0 || parts.is_empty() {
+        let seconds = nanos as f64 / 1e9;
+        parts.push(format!("{seconds}s"));
+    }
+
+    format!("{sign}{}", parts.join(" "))
+}
@@ -1,10 +1,316 @@
+use std::fmt;
+
+mod chem;
+mod dms;
+mod duration;
+mod num;
+mod parse;
+
+pub use chem::{molar_mass, ParseFormulaError};
+pub use dms::{parse_dms, ParseDmsError};
+pub use duration::{format_duration, parse_duration, ParseDurationError};
+pub use num::{ParseRatioError, Ratio, Real};
+pub use parse::ParseUnitError;
+
 #[derive(Copy, Clone, PartialEq, Debug)]
 pub enum Unit {
     Temperature(TempUnit),
     Length(LengthUnit),
     Area(AreaUnit),
     Volume(VolUnit),
-    Mass(MassUnit)
+    Mass(MassUnit),
+    Speed(SpeedUnit),
+    Time(TimeUnit),
+    Pressure(PressureUnit),
+    Energy(EnergyUnit),
+    Power(PowerUnit),
+    Data(DataUnit),
+    DataRate(DataRateUnit),
+    Angle(AngleUnit),
+    SolidAngle(SolidAngleUnit),
+    Force(ForceUnit),
+    Torque(TorqueUnit),
+    Frequency(FrequencyUnit),
+    FuelEconomy(FuelEconomyUnit),
+    Density(DensityUnit),
+    Concentration(ConcentrationUnit),
+    FlowRate(FlowRateUnit),
+    MassFlow(MassFlowUnit),
+    Charge(ChargeUnit),
+    Current(CurrentUnit),
+    Voltage(VoltageUnit),
+    Resistance(ResistanceUnit),
+    Conductance(ConductanceUnit),
+    Capacitance(CapacitanceUnit),
+    Inductance(InductanceUnit),
+    MagneticFluxDensity(MagneticFluxDensityUnit),
+    MagneticFlux(MagneticFluxUnit),
+    LuminousIntensity(LuminousIntensityUnit),
+    LuminousFlux(LuminousFluxUnit),
+    Illuminance(IlluminanceUnit),
+    Luminance(LuminanceUnit),
+    Radioactivity(RadioactivityUnit),
+    AbsorbedDose(AbsorbedDoseUnit),
+    EquivalentDose(EquivalentDoseUnit),
+    Acceleration(AccelerationUnit),
+    Amount(AmountUnit),
+}
+
+/// The physical quantity measured by a [`Unit`]. Conversions are only
+/// meaningful between units that share a dimension.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Dimension {
+    Temperature,
+    Length,
+    Area,
+    Volume,
+    Mass,
+    Speed,
+    Time,
+    Pressure,
+    Energy,
+    Power,
+    Data,
+    DataRate,
+    Angle,
+    SolidAngle,
+    Force,
+    Torque,
+    Frequency,
+    FuelEconomy,
+    Density,
+    Concentration,
+    FlowRate,
+    MassFlow,
+    Charge,
+    Current,
+    Voltage,
+    Resistance,
+    Conductance,
+    Capacitance,
+    Inductance,
+    MagneticFluxDensity,
+    MagneticFlux,
+    LuminousIntensity,
+    LuminousFlux,
+    Illuminance,
+    Luminance,
+    Radioactivity,
+    AbsorbedDose,
+    EquivalentDose,
+    Acceleration,
+    Amount,
+}
+
+impl Unit {
+    pub fn dimension(self) -> Dimension {
+        match self {
+            Unit::Temperature(_) => Dimension::Temperature,
+            Unit::Length(_) => Dimension::Length,
+            Unit::Area(_) => Dimension::Area,
+            Unit::Volume(_) => Dimension::Volume,
+            Unit::Mass(_) => Dimension::Mass,
+            Unit::Speed(_) => Dimension::Speed,
+            Unit::Time(_) => Dimension::Time,
+            Unit::Pressure(_) => Dimension::Pressure,
+            Unit::Energy(_) => Dimension::Energy,
+            Unit::Power(_) => Dimension::Power,
+            Unit::Data(_) => Dimension::Data,
+            Unit::DataRate(_) => Dimension::DataRate,
+            Unit::Angle(_) => Dimension::Angle,
+            Unit::SolidAngle(_) => Dimension::SolidAngle,
+            Unit::Force(_) => Dimension::Force,
+            Unit::Torque(_) => Dimension::Torque,
+            Unit::Frequency(_) => Dimension::Frequency,
+            Unit::FuelEconomy(_) => Dimension::FuelEconomy,
+            Unit::Density(_) => Dimension::Density,
+            Unit::Concentration(_) => Dimension::Concentration,
+            Unit::FlowRate(_) => Dimension::FlowRate,
+            Unit::MassFlow(_) => Dimension::MassFlow,
+            Unit::Charge(_) => Dimension::Charge,
+            Unit::Current(_) => Dimension::Current,
+            Unit::Voltage(_) => Dimension::Voltage,
+            Unit::Resistance(_) => Dimension::Resistance,
+            Unit::Conductance(_) => Dimension::Conductance,
+            Unit::Capacitance(_) => Dimension::Capacitance,
+            Unit::Inductance(_) => Dimension::Inductance,
+            Unit::MagneticFluxDensity(_) => Dimension::MagneticFluxDensity,
+            Unit::MagneticFlux(_) => Dimension::MagneticFlux,
+            Unit::LuminousIntensity(_) => Dimension::LuminousIntensity,
+            Unit::LuminousFlux(_) => Dimension::LuminousFlux,
+            Unit::Illuminance(_) => Dimension::Illuminance,
+            Unit::Luminance(_) => Dimension::Luminance,
+            Unit::Radioactivity(_) => Dimension::Radioactivity,
+            Unit::AbsorbedDose(_) => Dimension::AbsorbedDose,
+            Unit::EquivalentDose(_) => Dimension::EquivalentDose,
+            Unit::Acceleration(_) => Dimension::Acceleration,
+            Unit::Amount(_) => Dimension::Amount,
+        }
+    }
+
+    /// The power of ten carried by metric units, e.g. `-3` for millimeters.
+    fn scale(self) -> Option<i8> {
+        match self {
+            Unit::Length(LengthUnit::Meter(i))
+            | Unit::Area(AreaUnit::Meter2(i))
+            | Unit::Volume(VolUnit::Liter(i))
+            | Unit::Volume(VolUnit::Meter3(i))
+            | Unit::Mass(MassUnit::Gram(i))
+            | Unit::Speed(SpeedUnit::MeterPerSecond(i))
+            | Unit::Time(TimeUnit::Second(i))
+            | Unit::Pressure(PressureUnit::Pascal(i))
+            | Unit::Pressure(PressureUnit::Bar(i))
+            | Unit::Energy(EnergyUnit::Joule(i))
+            | Unit::Energy(EnergyUnit::WattHour(i))
+            | Unit::Energy(EnergyUnit::Calorie(i))
+            | Unit::Energy(EnergyUnit::Electronvolt(i))
+            | Unit::Power(PowerUnit::Watt(i))
+            | Unit::Data(DataUnit::Bit(i))
+            | Unit::Data(DataUnit::Byte(i))
+            | Unit::DataRate(DataRateUnit::BitPerSecond(i))
+            | Unit::DataRate(DataRateUnit::BytePerSecond(i))
+            | Unit::Angle(AngleUnit::Radian(i))
+            | Unit::SolidAngle(SolidAngleUnit::Steradian(i))
+            | Unit::Force(ForceUnit::Newton(i))
+            | Unit::Torque(TorqueUnit::NewtonMeter(i))
+            | Unit::Frequency(FrequencyUnit::Hertz(i))
+            | Unit::Concentration(ConcentrationUnit::MolePerLiter(i))
+            | Unit::Charge(ChargeUnit::Coulomb(i))
+            | Unit::Charge(ChargeUnit::AmpereHour(i))
+            | Unit::Current(CurrentUnit::Ampere(i))
+            | Unit::Voltage(VoltageUnit::Volt(i))
+            | Unit::Resistance(ResistanceUnit::Ohm(i))
+            | Unit::Conductance(ConductanceUnit::Siemens(i))
+            | Unit::Capacitance(CapacitanceUnit::Farad(i))
+            | Unit::Inductance(InductanceUnit::Henry(i))
+            | Unit::MagneticFluxDensity(MagneticFluxDensityUnit::Tesla(i))
+            | Unit::MagneticFlux(MagneticFluxUnit::Weber(i))
+            | Unit::LuminousIntensity(LuminousIntensityUnit::Candela(i))
+            | Unit::LuminousFlux(LuminousFluxUnit::Lumen(i))
+            | Unit::Illuminance(IlluminanceUnit::Lux(i))
+            | Unit::Radioactivity(RadioactivityUnit::Becquerel(i))
+            | Unit::Radioactivity(RadioactivityUnit::Curie(i))
+            | Unit::AbsorbedDose(AbsorbedDoseUnit::Gray(i))
+            | Unit::AbsorbedDose(AbsorbedDoseUnit::Rad(i))
+            | Unit::EquivalentDose(EquivalentDoseUnit::Sievert(i))
+            | Unit::EquivalentDose(EquivalentDoseUnit::Rem(i))
+            | Unit::Acceleration(AccelerationUnit::MeterPerSecond2(i))
+            | Unit::Acceleration(AccelerationUnit::Gal(i))
+            | Unit::Amount(AmountUnit::Mole(i)) => Some(i),
+            _ => None,
+        }
+    }
+
+    /// The power of two carried by units with a binary prefix, e.g. `20`
+    /// for mebibytes.
+    fn binary_scale(self) -> Option<u8> {
+        match self {
+            Unit::Data(DataUnit::BinaryBit(i))
+            | Unit::Data(DataUnit::BinaryByte(i))
+            | Unit::DataRate(DataRateUnit::BinaryBitPerSecond(i))
+            | Unit::DataRate(DataRateUnit::BinaryBytePerSecond(i)) => Some(i),
+            _ => None,
+        }
+    }
+
+    /// The units a compound unit is built from, e.g. kilograms and cubic
+    /// meters for kg/m³. Any other unit is its own only part.
+    fn parts(self) -> Vec<Unit> {
+        match self {
+            Unit::Density(DensityUnit::MassPerVolume(mass, vol)) => vec![Unit::Mass(mass), Unit::Volume(vol)],
+            Unit::FlowRate(FlowRateUnit::VolumePerTime(vol, time)) => vec![Unit::Volume(vol), Unit::Time(time)],
+            Unit::MassFlow(MassFlowUnit::MassPerTime(mass, time)) => vec![Unit::Mass(mass), Unit::Time(time)],
+            _ => vec![self],
+        }
+    }
+
+    /// Whether this unit, and every part of a compound one, exists in its
+    /// measuring system: there is no metric gallon.
+    fn exists(self) -> bool {
+        self.parts().into_iter().all(|unit| match unit {
+            Unit::Volume(
+                volume @ (VolUnit::TeaSpoon(_)
+                | VolUnit::TableSpoon(_)
+                | VolUnit::FluidOunce(_)
+                | VolUnit::Gill(_)
+                | VolUnit::Cup(_)
+                | VolUnit::Pint(_)
+                | VolUnit::Quart(_)
+                | VolUnit::Gallon(_)
+                | VolUnit::Peck(_)
+                | VolUnit::Bushel(_)),
+            ) => customary_volume(volume).is_some(),
+            _ => true,
+        })
+    }
+
+    /// Whether this counts moles, as amounts and molarities do. Those only
+    /// convert to masses and mass concentrations given a molar mass.
+    fn is_molar(self) -> bool {
+        matches!(self, Unit::Concentration(ConcentrationUnit::MolePerLiter(_)) | Unit::Amount(_))
+    }
+}
+
+/// The qualifier that goes before a unit's name, e.g. "imperial" gallons.
+impl fmt::Display for VolumeSystem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            VolumeSystem::UsLiquid => "US",
+            VolumeSystem::UsDry => "US dry",
+            VolumeSystem::Imperial => "imperial",
+            VolumeSystem::Metric => "metric",
+            VolumeSystem::Australian => "Australian",
+        };
+        f.write_str(name)
+    }
+}
+
+impl fmt::Display for Dimension {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Dimension::Temperature => "temperature",
+            Dimension::Length => "length",
+            Dimension::Area => "area",
+            Dimension::Volume => "volume",
+            Dimension::Mass => "mass",
+            Dimension::Speed => "speed",
+            Dimension::Time => "time",
+            Dimension::Pressure => "pressure",
+            Dimension::Energy => "energy",
+            Dimension::Power => "power",
+            Dimension::Data => "data",
+            Dimension::DataRate => "data rate",
+            Dimension::Angle => "angle",
+            Dimension::SolidAngle => "solid angle",
+            Dimension::Force => "force",
+            Dimension::Torque => "torque",
+            Dimension::Frequency => "frequency",
+            Dimension::FuelEconomy => "fuel economy",
+            Dimension::Density => "density",
+            Dimension::Concentration => "concentration",
+            Dimension::FlowRate => "flow rate",
+            Dimension::MassFlow => "mass flow",
+            Dimension::Charge => "electric charge",
+            Dimension::Current => "electric current",
+            Dimension::Voltage => "voltage",
+            Dimension::Resistance => "resistance",
+            Dimension::Conductance => "conductance",
+            Dimension::Capacitance => "capacitance",
+            Dimension::Inductance => "inductance",
+            Dimension::MagneticFluxDensity => "magnetic flux density",
+            Dimension::MagneticFlux => "magnetic flux",
+            Dimension::LuminousIntensity => "luminous intensity",
+            Dimension::LuminousFlux => "luminous flux",
+            Dimension::Illuminance => "illuminance",
+            Dimension::Luminance => "luminance",
+            Dimension::Radioactivity => "radioactivity",
+            Dimension::AbsorbedDose => "absorbed dose",
+            Dimension::EquivalentDose => "equivalent dose",
+            Dimension::Acceleration => "acceleration",
+            Dimension::Amount => "amount of substance",
+        };
+        f.write_str(name)
+    }
 }
 
 #[derive(Copy, Clone, PartialEq, Debug)]
@@ -21,7 +327,52 @@ pub enum LengthUnit {
     Inch,
     Feet,
     Yard,
-    Mile
+    Mile,
+    /// A thousandth of an inch, also called the thou.
+    Mil,
+
+    NauticalMile,
+    /// Six feet.
+    Fathom,
+
+    /// Gunter's chain, 66 feet or 100 links.
+    Chain,
+    Link,
+    /// A quarter chain, 16.5 feet.
+    Rod,
+    /// Ten chains.
+    Furlong,
+    /// Three miles.
+    League,
+    /// Exactly 1200/3937 m, slightly longer than the international foot.
+    /// US land surveys and state plane coordinates may still use it.
+    SurveyFoot,
+    /// 5280 survey feet.
+    SurveyMile,
+
+    /// 10⁻¹⁰ m.
+    Angstrom,
+    AstronomicalUnit,
+    /// The distance light travels in a Julian year.
+    LightYear,
+    /// The distance at which one astronomical unit subtends one arcsecond,
+    /// 648000/π au.
+    Parsec,
+
+    /// The DTP or PostScript point, 1/72 inch.
+    Point,
+    /// Twelve points.
+    Pica,
+    /// The continental European point, taken as 0.376 mm.
+    DidotPoint,
+    /// Twelve Didot points.
+    Cicero,
+    /// A twentieth of a point, as used by word processors.
+    Twip,
+    /// The font size, which [`ConversionContext::font_size`] sets.
+    Em,
+    /// One dot at the resolution [`ConversionContext::dpi`] sets.
+    Pixel,
 }
 
 #[derive(Copy, Clone, PartialEq, Debug)]
@@ -42,11 +393,41 @@ pub enum VolUnit {
     Liter(i8),
     Meter3(i8),
 
-    TeaSpoon,
-    TableSpoon,
-    Cup,
-    Pint,
-    Gallon
+    /// Metric by default; US and imperial spoons are fractions of their
+    /// fluid ounces.
+    TeaSpoon(VolumeSystem),
+    /// Metric by default. The Australian tablespoon is 20 mL.
+    TableSpoon(VolumeSystem),
+    FluidOunce(VolumeSystem),
+    Gill(VolumeSystem),
+    Cup(VolumeSystem),
+    Pint(VolumeSystem),
+    Quart(VolumeSystem),
+    Gallon(VolumeSystem),
+    /// Two dry gallons.
+    Peck(VolumeSystem),
+    /// Eight dry gallons.
+    Bushel(VolumeSystem),
+
+    Inch3,
+    Feet3,
+    Yard3,
+    /// Oil barrel, 42 US gallons.
+    Barrel,
+}
+
+/// The system a customary volume is measured in. Not every unit exists in
+/// every system: there are no metric gallons, and only the tablespoon has
+/// an Australian size.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum VolumeSystem {
+    UsLiquid,
+    /// Pints, quarts and gallons for grain and produce, and the peck and
+    /// bushel.
+    UsDry,
+    Imperial,
+    Metric,
+    Australian,
 }
 
 #[derive(Copy, Clone, PartialEq, Debug)]
@@ -55,19 +436,557 @@ pub enum MassUnit {
 
     Ounce,
     Pound,
+    /// Imperial, 14 pounds.
     Stone,
+    /// US hundredweight, 100 pounds.
+    ShortHundredweight,
+    /// Imperial hundredweight, 112 pounds.
+    LongHundredweight,
+    /// US ton, 2000 pounds.
+    ShortTon,
+    /// Imperial ton, 2240 pounds.
+    LongTon,
+    /// Metric ton, 1000 kg.
+    Tonne,
+    /// Avoirdupois dram, 1/16 ounce.
+    Dram,
+    /// 64.79891 mg, the same in the avoirdupois, troy and apothecaries'
+    /// systems.
+    Grain,
+    /// Troy ounce, 480 grains, used for precious metals. Not to be confused
+    /// with the avoirdupois [`MassUnit::Ounce`].
+    TroyOunce,
+    /// Troy pound, 12 troy ounces.
+    TroyPound,
+    /// 24 grains, 1/20 troy ounce.
+    Pennyweight,
+    /// Apothecaries' dram, 60 grains.
+    ApothecaryDram,
+    /// Apothecaries' scruple, 20 grains.
+    Scruple,
+    /// Metric carat, 200 mg.
+    Carat,
+    /// The mass a pound-force accelerates at 1 ft/s².
+    Slug,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum SpeedUnit {
+    MeterPerSecond(i8),
+
+    KilometerPerHour,
+    MilePerHour,
+    FootPerSecond,
+    Knot,
+    /// Speed of sound at sea level in the standard atmosphere (15 °C).
+    Mach,
+    /// Speed of light in vacuum, for expressing fractions of `c`.
+    SpeedOfLight,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum TimeUnit {
+    Second(i8),
+
+    Minute,
+    Hour,
+    Day,
+    Week,
+    /// 365.25 days, as used in astronomy.
+    JulianYear,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum PressureUnit {
+    Pascal(i8),
+    Bar(i8),
+
+    Atmosphere,
+    Psi,
+    /// Pounds per square inch above [`ConversionContext::reference_pressure`].
+    Psig,
+    MillimeterOfMercury,
+    InchOfMercury,
+    Torr,
+    KilogramForcePerCm2,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum EnergyUnit {
+    Joule(i8),
+    WattHour(i8),
+    /// Thermochemical calorie, 4.184 J. `Calorie(3)` is the kilocalorie.
+    Calorie(i8),
+    Electronvolt(i8),
+
+    /// International Steam Table calorie, 4.1868 J.
+    CalorieIt,
+    KilocalorieIt,
+    /// The nutritional "Calorie", one thermochemical kilocalorie.
+    FoodCalorie,
+    BtuIt,
+    BtuThermochemical,
+    Erg,
+    /// 100,000 BTU (IT).
+    Therm,
+    FootPound,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum PowerUnit {
+    Watt(i8),
+
+    /// 550 foot-pounds per second.
+    HorsepowerMechanical,
+    /// 75 kilogram-force meters per second, the German "PS".
+    HorsepowerMetric,
+    /// Exactly 746 W.
+    HorsepowerElectrical,
+    HorsepowerBoiler,
+    BtuPerHour,
+    /// 12,000 BTU (IT) per hour.
+    TonOfRefrigeration,
+    ErgPerSecond,
+    /// Decibels relative to one milliwatt.
+    DecibelMilliwatt,
+    /// Decibels relative to one watt.
+    DecibelWatt,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum DataUnit {
+    Bit(i8),
+    Byte(i8),
+    /// Bits with an IEC binary prefix: `BinaryBit(10)` is the kibibit.
+    BinaryBit(u8),
+    /// Bytes with an IEC binary prefix: `BinaryByte(20)` is the mebibyte.
+    BinaryByte(u8),
+
+    Nibble,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum DataRateUnit {
+    BitPerSecond(i8),
+    BytePerSecond(i8),
+    /// See [`DataUnit::BinaryBit`].
+    BinaryBitPerSecond(u8),
+    /// See [`DataUnit::BinaryByte`].
+    BinaryBytePerSecond(u8),
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum AngleUnit {
+    /// `Radian(-3)` is the milliradian.
+    Radian(i8),
+
+    Degree,
+    Arcminute,
+    Arcsecond,
+    /// 1/400 of a turn, also called the gon.
+    Gradian,
+    Turn,
+    /// 1/6400 of a turn, the angular mil used by NATO.
+    MilNato,
+    /// 1/6000 of a turn.
+    MilWarsawPact,
+    /// 1/6300 of a turn, the Swedish "streck".
+    MilSwedish,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum SolidAngleUnit {
+    Steradian(i8),
+
+    /// (π/180)² steradians.
+    SquareDegree,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum ForceUnit {
+    Newton(i8),
+
+    Dyne,
+    /// The weight of one kilogram under standard gravity, also the kilopond.
+    KilogramForce,
+    PoundForce,
+    /// The force that accelerates one pound by one foot per second squared.
+    Poundal,
+    /// 1000 pounds-force.
+    Kip,
+}
+
+/// Moments of force. These share their SI dimensions with [`EnergyUnit`]
+/// but are a different quantity, so the two never convert into each other.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum TorqueUnit {
+    NewtonMeter(i8),
+
+    PoundForceFoot,
+    PoundForceInch,
+    KilogramForceMeter,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum FrequencyUnit {
+    Hertz(i8),
+
+    RevolutionPerMinute,
+    /// Angular frequency: one hertz is 2π rad/s.
+    RadianPerSecond,
+    CyclePerMinute,
+    BeatPerMinute,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum FuelEconomyUnit {
+    /// Fuel used rather than distance covered, so it falls as economy
+    /// improves.
+    LitersPer100Km,
+    KilometersPerLiter,
+    MilesPerGallonUs,
+    MilesPerGallonUk,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum DensityUnit {
+    /// Any mass over any volume: `MassPerVolume(MassUnit::Gram(3),
+    /// VolUnit::Meter3(0))` is kg/m³. Mass concentrations such as mg/L are
+    /// written this way too.
+    MassPerVolume(MassUnit, VolUnit),
+
+    /// Relative to water at its densest, 999.972 kg/m³ at 4 °C.
+    SpecificGravity,
+}
+
+/// How much of a solute a solution holds. These convert to and from mass
+/// concentrations, which are [`DensityUnit::MassPerVolume`] units such as
+/// mg/L.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum ConcentrationUnit {
+    /// Molarity: `MolePerLiter(-3)` is mmol/L. Converting it to a mass
+    /// concentration needs [`ConversionContext::molar_mass`].
+    MolePerLiter(i8),
+
+    /// Parts per million by mass of a dilute solution in water, 1 mg/L.
+    PartsPerMillion,
+    /// 1 µg/L.
+    PartsPerBillion,
+    /// Grams per 100 mL.
+    PercentWeightPerVolume,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum FlowRateUnit {
+    /// Any volume over any time: `VolumePerTime(VolUnit::Gallon(UsLiquid),
+    /// TimeUnit::Minute)` is US gallons per minute.
+    VolumePerTime(VolUnit, TimeUnit),
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum MassFlowUnit {
+    /// Any mass over any time: `MassPerTime(MassUnit::Gram(3),
+    /// TimeUnit::Hour)` is kilograms per hour.
+    MassPerTime(MassUnit, TimeUnit),
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum ChargeUnit {
+    Coulomb(i8),
+
+    /// `AmpereHour(-3)` is the milliampere-hour of battery capacities.
+    AmpereHour(i8),
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum CurrentUnit {
+    Ampere(i8),
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum VoltageUnit {
+    Volt(i8),
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum ResistanceUnit {
+    Ohm(i8),
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum ConductanceUnit {
+    Siemens(i8),
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum CapacitanceUnit {
+    Farad(i8),
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum InductanceUnit {
+    Henry(i8),
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum MagneticFluxDensityUnit {
+    Tesla(i8),
+
+    /// CGS unit, 10⁻⁴ T.
+    Gauss,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum MagneticFluxUnit {
+    Weber(i8),
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum LuminousIntensityUnit {
+    Candela(i8),
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum LuminousFluxUnit {
+    Lumen(i8),
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum IlluminanceUnit {
+    Lux(i8),
+
+    /// One lumen per square foot.
+    FootCandle,
+    /// CGS unit, one lumen per square centimeter.
+    Phot,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum LuminanceUnit {
+    /// One candela per square meter, as used for screen brightness.
+    Nit,
+    /// One candela per square foot, divided by π.
+    FootLambert,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum RadioactivityUnit {
+    Becquerel(i8),
+
+    /// 3.7 × 10¹⁰ Bq, roughly the activity of a gram of radium.
+    Curie(i8),
+    /// 10⁶ Bq.
+    Rutherford,
+    DisintegrationPerMinute,
+}
+
+/// Energy absorbed per mass of matter. Not convertible to
+/// [`EquivalentDoseUnit`], which weighs it by its biological effect.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum AbsorbedDoseUnit {
+    Gray(i8),
+
+    /// 0.01 Gy. Its symbol is also the radian's, so "rad" only means this
+    /// where an absorbed dose is expected; see [`Unit::parse_as`]. It is
+    /// printed as "rad_dose", which always does.
+    Rad(i8),
+}
+
+/// Absorbed dose weighted by the harm done to tissue.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum EquivalentDoseUnit {
+    Sievert(i8),
+
+    /// Roentgen equivalent man, 0.01 Sv.
+    Rem(i8),
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum AccelerationUnit {
+    MeterPerSecond2(i8),
+
+    FootPerSecond2,
+    /// Standard gravity g₀, 9.80665 m/s², for g-force readings.
+    StandardGravity,
+    /// The galileo of geophysics, 0.01 m/s². `Gal(-3)` is the milligal.
+    Gal(i8),
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum AmountUnit {
+    /// Converting moles to a mass needs [`ConversionContext::molar_mass`].
+    Mole(i8),
+    /// A count of atoms or molecules, 6.02214076 × 10²³ per mole.
+    Particle,
 }
 
 
-fn scale_to_string(scale: i8) -> String {
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum ConversionError {
+    /// The two units measure different things, e.g. kelvin and miles.
+    IncompatibleDimensions(Dimension, Dimension),
+    /// The input value is NaN or infinite.
+    NonFiniteValue,
+    /// The conversion overflowed or otherwise produced NaN or infinity.
+    NonFiniteResult,
+    /// An exact conversion needed a fraction too large to represent.
+    Overflow,
+    /// A metric unit carries a power of ten that has no named prefix.
+    UnsupportedScale(i8),
+    /// A data unit carries a power of two that has no named binary prefix.
+    UnsupportedBinaryScale(u8),
+    /// An exact conversion has an irrational or logarithmic factor, as
+    /// between degrees and radians or between dBm and watts.
+    Inexact,
+    /// A molarity or amount was converted to or from a mass concentration
+    /// or mass without setting [`ConversionContext::molar_mass`].
+    MolarMassRequired,
+    /// A customary unit was given a system it does not exist in, such as
+    /// `VolUnit::Gallon(VolumeSystem::Metric)`.
+    UnsupportedSystem(Unit),
+    /// Pixels were converted with a [`ConversionContext::dpi`] that is not
+    /// positive.
+    InvalidDpi,
+    /// Ems were converted with a [`ConversionContext::font_size`] that is
+    /// not positive.
+    InvalidFontSize,
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConversionError::IncompatibleDimensions(a, b) => {
+                write!(f, "Cannot convert {a} to {b}")
+            }
+            ConversionError::NonFiniteValue => write!(f, "Value must be a finite number"),
+            ConversionError::NonFiniteResult => write!(f, "Conversion result is not a finite number"),
+            ConversionError::Overflow => write!(f, "Exact conversion overflowed"),
+            ConversionError::UnsupportedScale(i) => write!(f, "Unsupported metric scale 10^{i}"),
+            ConversionError::UnsupportedBinaryScale(i) => write!(f, "Unsupported binary scale 2^{i}"),
+            ConversionError::Inexact => write!(f, "Conversion has no exact fractional result"),
+            ConversionError::UnsupportedSystem(unit) => write!(f, "There are no {unit}"),
+            ConversionError::MolarMassRequired => write!(f, "Conversion needs the molar mass of the substance"),
+            ConversionError::InvalidDpi => write!(f, "Resolution must be a positive number of dots per inch"),
+            ConversionError::InvalidFontSize => write!(f, "Font size must be a positive number of points"),
+        }
+    }
+}
+
+impl std::error::Error for ConversionError {}
+
+/// Every power of ten that has a named SI prefix, from quecto to quetta.
+const PREFIX_SCALES: [i8; 25] = [
+    -30, -27, -24, -21, -18, -15, -12, -9, -6, -3, -2, -1,
+    0,
+    1, 2, 3, 6, 9, 12, 15, 18, 21, 24, 27, 30,
+];
+
+fn scale_to_string(scale: i8) -> Option<&'static str> {
+    match scale {
+        -30 => Some("quecto"),
+        -27 => Some("ronto"),
+        -24 => Some("yocto"),
+        -21 => Some("zepto"),
+        -18 => Some("atto"),
+        -15 => Some("femto"),
+        -12 => Some("pico"),
+        -9 => Some("nano"),
+        -6 => Some("micro"),
+        -3 => Some("milli"),
+        -2 => Some("centi"),
+        -1 => Some("deci"),
+        0 => Some(""),
+        1 => Some("deca"),
+        2 => Some("hecto"),
+        3 => Some("kilo"),
+        6 => Some("mega"),
+        9 => Some("giga"),
+        12 => Some("tera"),
+        15 => Some("peta"),
+        18 => Some("exa"),
+        21 => Some("zetta"),
+        24 => Some("yotta"),
+        27 => Some("ronna"),
+        30 => Some("quetta"),
+        _ => None,
+    }
+}
+
+fn scale_to_symbol(scale: i8) -> Option<&'static str> {
+    match scale {
+        -30 => Some("q"),
+        -27 => Some("r"),
+        -24 => Some("y"),
+        -21 => Some("z"),
+        -18 => Some("a"),
+        -15 => Some("f"),
+        -12 => Some("p"),
+        -9 => Some("n"),
+        -6 => Some("µ"),
+        -3 => Some("m"),
+        -2 => Some("c"),
+        -1 => Some("d"),
+        0 => Some(""),
+        1 => Some("da"),
+        2 => Some("h"),
+        3 => Some("k"),
+        6 => Some("M"),
+        9 => Some("G"),
+        12 => Some("T"),
+        15 => Some("P"),
+        18 => Some("E"),
+        21 => Some("Z"),
+        24 => Some("Y"),
+        27 => Some("R"),
+        30 => Some("Q"),
+        _ => None,
+    }
+}
+
+/// Every power of two that has a named IEC binary prefix, from kibi to yobi.
+const BINARY_PREFIX_SCALES: [u8; 8] = [10, 20, 30, 40, 50, 60, 70, 80];
+
+fn binary_scale_to_string(scale: u8) -> Option<&'static str> {
+    match scale {
+        10 => Some("kibi"),
+        20 => Some("mebi"),
+        30 => Some("gibi"),
+        40 => Some("tebi"),
+        50 => Some("pebi"),
+        60 => Some("exbi"),
+        70 => Some("zebi"),
+        80 => Some("yobi"),
+        _ => None,
+    }
+}
+
+fn binary_scale_to_symbol(scale: u8) -> Option<&'static str> {
     match scale {
-        -3 => "milli".to_string(),
-        -2 => "centi".to_string(),
-        3 => "kilo".to_string(),
-        _ => "".to_string(),
+        10 => Some("Ki"),
+        20 => Some("Mi"),
+        30 => Some("Gi"),
+        40 => Some("Ti"),
+        50 => Some("Pi"),
+        60 => Some("Ei"),
+        70 => Some("Zi"),
+        80 => Some("Yi"),
+        _ => None,
     }
 }
 
+/// The name of a unit such as kg/m³, "kilograms per cubic meter".
+fn per_name(numerator: Unit, denominator: Unit) -> String {
+    let denominator = unit_to_string(denominator);
+    let singular = if let Some(stem) = denominator.strip_suffix("feet") {
+        format!("{stem}foot")
+    } else if let Some(stem) = denominator.strip_suffix("inches") {
+        format!("{stem}inch")
+    } else {
+        denominator.strip_suffix('s').unwrap_or(&denominator).to_string()
+    };
+    format!("{} per {}", unit_to_string(numerator), singular)
+}
+
 fn unit_to_string(unit: Unit) -> String {
     match unit {
         Unit::Temperature(temp) => match temp {
@@ -78,19 +997,40 @@ fn unit_to_string(unit: Unit) -> String {
 
         Unit::Length(length) => match length {
             LengthUnit::Meter(i) => {
-                let prefix = scale_to_string(i);
+                let prefix = scale_to_string(i).unwrap_or_default();
                 format!("{}meters", prefix)
             }
             LengthUnit::Inch => "inches".to_string(),
             LengthUnit::Feet => "feet".to_string(),
             LengthUnit::Yard => "yards".to_string(),
             LengthUnit::Mile => "miles".to_string(),
+            LengthUnit::Mil => "mils".to_string(),
+            LengthUnit::NauticalMile => "nautical miles".to_string(),
+            LengthUnit::Fathom => "fathoms".to_string(),
+            LengthUnit::Chain => "chains".to_string(),
+            LengthUnit::Link => "links".to_string(),
+            LengthUnit::Rod => "rods".to_string(),
+            LengthUnit::Furlong => "furlongs".to_string(),
+            LengthUnit::League => "leagues".to_string(),
+            LengthUnit::SurveyFoot => "US survey feet".to_string(),
+            LengthUnit::SurveyMile => "US survey miles".to_string(),
+            LengthUnit::Angstrom => "ångströms".to_string(),
+            LengthUnit::AstronomicalUnit => "astronomical units".to_string(),
+            LengthUnit::LightYear => "light-years".to_string(),
+            LengthUnit::Parsec => "parsecs".to_string(),
+            LengthUnit::Point => "points".to_string(),
+            LengthUnit::Pica => "picas".to_string(),
+            LengthUnit::DidotPoint => "Didot points".to_string(),
+            LengthUnit::Cicero => "ciceros".to_string(),
+            LengthUnit::Twip => "twips".to_string(),
+            LengthUnit::Em => "ems".to_string(),
+            LengthUnit::Pixel => "pixels".to_string(),
         }
 
         Unit::Area(area) => match area {
             AreaUnit::Meter2(i) => {
-                let prefix = scale_to_string(i);
-                format!("square {}meter", prefix)
+                let prefix = scale_to_string(i).unwrap_or_default();
+                format!("square {}meters", prefix)
             }
             AreaUnit::Inch2 => "square inches".to_string(),
             AreaUnit::Feet2 => "square feet".to_string(),
@@ -102,206 +1042,1903 @@ fn unit_to_string(unit: Unit) -> String {
 
         Unit::Volume(vol) => match vol {
             VolUnit::Liter(i) => {
-                let prefix = scale_to_string(i);
+                let prefix = scale_to_string(i).unwrap_or_default();
                 format!("{}liters", prefix)
             }
             VolUnit::Meter3(i) => {
-                let prefix = scale_to_string(i);
+                let prefix = scale_to_string(i).unwrap_or_default();
                 format!("cubic {}meters", prefix)
             }
-            VolUnit::TeaSpoon => "tea spoons".to_string(),
-            VolUnit::TableSpoon => "table spoons".to_string(),
-            VolUnit::Cup => "cups".to_string(),
-            VolUnit::Pint => "pints".to_string(),
-            VolUnit::Gallon => "gallons".to_string()
+            VolUnit::TeaSpoon(system) => format!("{system} teaspoons"),
+            VolUnit::TableSpoon(system) => format!("{system} tablespoons"),
+            VolUnit::FluidOunce(system) => format!("{system} fluid ounces"),
+            VolUnit::Gill(system) => format!("{system} gills"),
+            VolUnit::Cup(system) => format!("{system} cups"),
+            VolUnit::Pint(system) => format!("{system} pints"),
+            VolUnit::Quart(system) => format!("{system} quarts"),
+            VolUnit::Gallon(system) => format!("{system} gallons"),
+            VolUnit::Peck(system) => format!("{system} pecks"),
+            VolUnit::Bushel(system) => format!("{system} bushels"),
+            VolUnit::Inch3 => "cubic inches".to_string(),
+            VolUnit::Feet3 => "cubic feet".to_string(),
+            VolUnit::Yard3 => "cubic yards".to_string(),
+            VolUnit::Barrel => "barrels".to_string(),
         }
 
         Unit::Mass(mass) => match mass {
             MassUnit::Gram(i) => {
-                let prefix = scale_to_string(i);
+                let prefix = scale_to_string(i).unwrap_or_default();
                 format!("{}grams", prefix)
             }
-            MassUnit::Ounce => "ounce".to_string(),
+            MassUnit::Ounce => "ounces".to_string(),
             MassUnit::Pound => "pounds".to_string(),
             MassUnit::Stone => "stones".to_string(),
+            MassUnit::ShortHundredweight => "short hundredweights".to_string(),
+            MassUnit::LongHundredweight => "long hundredweights".to_string(),
+            MassUnit::ShortTon => "short tons".to_string(),
+            MassUnit::LongTon => "long tons".to_string(),
+            MassUnit::Tonne => "tonnes".to_string(),
+            MassUnit::Dram => "drams".to_string(),
+            MassUnit::Grain => "grains".to_string(),
+            MassUnit::TroyOunce => "troy ounces".to_string(),
+            MassUnit::TroyPound => "troy pounds".to_string(),
+            MassUnit::Pennyweight => "pennyweights".to_string(),
+            MassUnit::ApothecaryDram => "apothecaries' drams".to_string(),
+            MassUnit::Scruple => "scruples".to_string(),
+            MassUnit::Carat => "carats".to_string(),
+            MassUnit::Slug => "slugs".to_string(),
         }
-    }
-}
 
-fn power_of(mut i: i8) -> f32 {
-    let mut scale = 1.0;
-    if i < 0 {
-        while i < 0 {
-            scale *= 0.1;
-            i += 1;
-        }
-    } else if i > 0 {
-        while i > 0 {
-            scale *= 10.0;
-            i -= 1;
+        Unit::Speed(speed) => match speed {
+            SpeedUnit::MeterPerSecond(i) => {
+                let prefix = scale_to_string(i).unwrap_or_default();
+                format!("{}meters per second", prefix)
+            }
+            SpeedUnit::KilometerPerHour => "kilometers per hour".to_string(),
+            SpeedUnit::MilePerHour => "miles per hour".to_string(),
+            SpeedUnit::FootPerSecond => "feet per second".to_string(),
+            SpeedUnit::Knot => "knots".to_string(),
+            SpeedUnit::Mach => "mach".to_string(),
+            SpeedUnit::SpeedOfLight => "speed of light".to_string(),
         }
-    }
-
-    scale
-}
 
-fn convert_to_standard(value: f32, unit: Unit) -> f32 {
-    match unit {
-        Unit::Temperature(temp) => match temp {
-            TempUnit::Kelvin => value,
-            TempUnit::Celsius => value + 273.15,
-            TempUnit::Fahrenheit => (value - 32.0) / 1.8 + 273.15,
+        Unit::Time(time) => match time {
+            TimeUnit::Second(i) => {
+                let prefix = scale_to_string(i).unwrap_or_default();
+                format!("{}seconds", prefix)
+            }
+            TimeUnit::Minute => "minutes".to_string(),
+            TimeUnit::Hour => "hours".to_string(),
+            TimeUnit::Day => "days".to_string(),
+            TimeUnit::Week => "weeks".to_string(),
+            TimeUnit::JulianYear => "julian years".to_string(),
         }
 
-        Unit::Length(length) => match length {
-            LengthUnit::Meter(i) => {
-                let scale = power_of(i);
-                value * scale
+        Unit::Pressure(pressure) => match pressure {
+            PressureUnit::Pascal(i) => {
+                let prefix = scale_to_string(i).unwrap_or_default();
+                format!("{}pascals", prefix)
+            }
+            PressureUnit::Bar(i) => {
+                let prefix = scale_to_string(i).unwrap_or_default();
+                format!("{}bars", prefix)
             }
+            PressureUnit::Atmosphere => "atmospheres".to_string(),
+            PressureUnit::Psi => "pounds per square inch".to_string(),
+            PressureUnit::Psig => "pounds per square inch gauge".to_string(),
+            PressureUnit::MillimeterOfMercury => "millimeters of mercury".to_string(),
+            PressureUnit::InchOfMercury => "inches of mercury".to_string(),
+            PressureUnit::Torr => "torr".to_string(),
+            PressureUnit::KilogramForcePerCm2 => "kilograms-force per square centimeter".to_string(),
+        }
 
-            LengthUnit::Inch => value * 0.0254,
-            LengthUnit::Feet => value * 0.3048,
-            LengthUnit::Yard => value * 0.9144,
-            LengthUnit::Mile => value * 1609.344,
+        Unit::Energy(energy) => match energy {
+            EnergyUnit::Joule(i) => {
+                let prefix = scale_to_string(i).unwrap_or_default();
+                format!("{}joules", prefix)
+            }
+            EnergyUnit::WattHour(i) => {
+                let prefix = scale_to_string(i).unwrap_or_default();
+                format!("{}watt-hours", prefix)
+            }
+            EnergyUnit::Calorie(i) => {
+                let prefix = scale_to_string(i).unwrap_or_default();
+                format!("{}calories", prefix)
+            }
+            EnergyUnit::Electronvolt(i) => {
+                let prefix = scale_to_string(i).unwrap_or_default();
+                format!("{}electronvolts", prefix)
+            }
+            EnergyUnit::CalorieIt => "IT calories".to_string(),
+            EnergyUnit::KilocalorieIt => "IT kilocalories".to_string(),
+            EnergyUnit::FoodCalorie => "food calories".to_string(),
+            EnergyUnit::BtuIt => "british thermal units".to_string(),
+            EnergyUnit::BtuThermochemical => "thermochemical british thermal units".to_string(),
+            EnergyUnit::Erg => "ergs".to_string(),
+            EnergyUnit::Therm => "therms".to_string(),
+            EnergyUnit::FootPound => "foot-pounds".to_string(),
         }
 
-        Unit::Area(area) => match area {
-            AreaUnit::Meter2(i) => {
-                let scale = power_of(i*2);
-                value * scale
+        Unit::Power(power) => match power {
+            PowerUnit::Watt(i) => {
+                let prefix = scale_to_string(i).unwrap_or_default();
+                format!("{}watts", prefix)
             }
+            PowerUnit::HorsepowerMechanical => "mechanical horsepower".to_string(),
+            PowerUnit::HorsepowerMetric => "metric horsepower".to_string(),
+            PowerUnit::HorsepowerElectrical => "electrical horsepower".to_string(),
+            PowerUnit::HorsepowerBoiler => "boiler horsepower".to_string(),
+            PowerUnit::BtuPerHour => "british thermal units per hour".to_string(),
+            PowerUnit::TonOfRefrigeration => "tons of refrigeration".to_string(),
+            PowerUnit::ErgPerSecond => "ergs per second".to_string(),
+            PowerUnit::DecibelMilliwatt => "decibel-milliwatts".to_string(),
+            PowerUnit::DecibelWatt => "decibel-watts".to_string(),
+        }
 
-            AreaUnit::Inch2 => value * 0.0254 * 0.0254,
-            AreaUnit::Feet2 => value * 0.3048 * 0.3048,
-            AreaUnit::Yard2 => value * 0.9144 * 0.9144,
-            AreaUnit::Mile2 => value * 1609.344 * 1609.344,
-            AreaUnit::Acre => value * 4046.8564224,
-            AreaUnit::Hectare => value * 10000.0,
+        Unit::Data(data) => match data {
+            DataUnit::Bit(i) => {
+                let prefix = scale_to_string(i).unwrap_or_default();
+                format!("{}bits", prefix)
+            }
+            DataUnit::Byte(i) => {
+                let prefix = scale_to_string(i).unwrap_or_default();
+                format!("{}bytes", prefix)
+            }
+            DataUnit::BinaryBit(i) => {
+                let prefix = binary_scale_to_string(i).unwrap_or_default();
+                format!("{}bits", prefix)
+            }
+            DataUnit::BinaryByte(i) => {
+                let prefix = binary_scale_to_string(i).unwrap_or_default();
+                format!("{}bytes", prefix)
+            }
+            DataUnit::Nibble => "nibbles".to_string(),
         }
 
-        Unit::Volume(vol) => match vol {
-            VolUnit::Liter(i) => {
-                let scale = power_of(i);
-                value * scale
+        Unit::DataRate(rate) => match rate {
+            DataRateUnit::BitPerSecond(i) => {
+                let prefix = scale_to_string(i).unwrap_or_default();
+                format!("{}bits per second", prefix)
             }
-            VolUnit::Meter3(i) => {
-                let scale = power_of(i*3);
-                value * scale * 1000.0
+            DataRateUnit::BytePerSecond(i) => {
+                let prefix = scale_to_string(i).unwrap_or_default();
+                format!("{}bytes per second", prefix)
+            }
+            DataRateUnit::BinaryBitPerSecond(i) => {
+                let prefix = binary_scale_to_string(i).unwrap_or_default();
+                format!("{}bits per second", prefix)
+            }
+            DataRateUnit::BinaryBytePerSecond(i) => {
+                let prefix = binary_scale_to_string(i).unwrap_or_default();
+                format!("{}bytes per second", prefix)
             }
-            VolUnit::TeaSpoon => value * 0.005,
-            VolUnit::TableSpoon => value * 0.015,
-            VolUnit::Cup => value * 0.2365882365,
-            VolUnit::Pint => value * 0.473176473,
-            VolUnit::Gallon => value * 3.785411784,
         }
 
-        Unit::Mass(mass) => match mass {
-            MassUnit::Gram(i) => {
-                let scale = power_of(i);
-                value * scale
+        Unit::Angle(angle) => match angle {
+            AngleUnit::Radian(i) => {
+                let prefix = scale_to_string(i).unwrap_or_default();
+                format!("{}radians", prefix)
             }
-            MassUnit::Ounce => value * 28.34952,
-            MassUnit::Pound => value * 453.59237,
-            MassUnit::Stone => value * 453.59237 * 14.0,
+            AngleUnit::Degree => "degrees".to_string(),
+            AngleUnit::Arcminute => "arcminutes".to_string(),
+            AngleUnit::Arcsecond => "arcseconds".to_string(),
+            AngleUnit::Gradian => "gradians".to_string(),
+            AngleUnit::Turn => "turns".to_string(),
+            AngleUnit::MilNato => "NATO mils".to_string(),
+            AngleUnit::MilWarsawPact => "Warsaw Pact mils".to_string(),
+            AngleUnit::MilSwedish => "Swedish mils".to_string(),
         }
-    }
-}
 
-fn convert_from_standard(value: f32, unit: Unit) -> f32 {
-    match unit {
-        Unit::Temperature(temp) => match temp {
-            TempUnit::Kelvin => value,
-            TempUnit::Celsius => value - 273.15,
-            TempUnit::Fahrenheit => (value * 1.8) - 459.67,
+        Unit::SolidAngle(solid) => match solid {
+            SolidAngleUnit::Steradian(i) => {
+                let prefix = scale_to_string(i).unwrap_or_default();
+                format!("{}steradians", prefix)
+            }
+            SolidAngleUnit::SquareDegree => "square degrees".to_string(),
         }
 
-        Unit::Length(length) => match length {
-            LengthUnit::Meter(i) => {
-                let scale = power_of(-i);
-                value * scale
+        Unit::Force(force) => match force {
+            ForceUnit::Newton(i) => {
+                let prefix = scale_to_string(i).unwrap_or_default();
+                format!("{}newtons", prefix)
             }
+            ForceUnit::Dyne => "dynes".to_string(),
+            ForceUnit::KilogramForce => "kilograms-force".to_string(),
+            ForceUnit::PoundForce => "pounds-force".to_string(),
+            ForceUnit::Poundal => "poundals".to_string(),
+            ForceUnit::Kip => "kips".to_string(),
+        }
 
-            LengthUnit::Inch => value / 0.0254,
-            LengthUnit::Feet => value / 0.3048,
-            LengthUnit::Yard => value / 0.9144,
-            LengthUnit::Mile => value / 1609.344,
+        Unit::Torque(torque) => match torque {
+            TorqueUnit::NewtonMeter(i) => {
+                let prefix = scale_to_string(i).unwrap_or_default();
+                format!("{}newton-meters", prefix)
+            }
+            TorqueUnit::PoundForceFoot => "pound-force feet".to_string(),
+            TorqueUnit::PoundForceInch => "pound-force inches".to_string(),
+            TorqueUnit::KilogramForceMeter => "kilogram-force meters".to_string(),
         }
 
-        Unit::Area(area) => match area {
-            AreaUnit::Meter2(i) => {
-                let scale = power_of(-i*2);
-                value * scale
+        Unit::Frequency(frequency) => match frequency {
+            FrequencyUnit::Hertz(i) => {
+                let prefix = scale_to_string(i).unwrap_or_default();
+                format!("{}hertz", prefix)
             }
+            FrequencyUnit::RevolutionPerMinute => "revolutions per minute".to_string(),
+            FrequencyUnit::RadianPerSecond => "radians per second".to_string(),
+            FrequencyUnit::CyclePerMinute => "cycles per minute".to_string(),
+            FrequencyUnit::BeatPerMinute => "beats per minute".to_string(),
+        }
 
-            AreaUnit::Inch2 => value / (0.0254 * 0.0254),
-            AreaUnit::Feet2 => value / (0.3048 * 0.3048),
-            AreaUnit::Yard2 => value / (0.9144 * 0.9144),
-            AreaUnit::Mile2 => value / (1609.344 * 1609.344),
-            AreaUnit::Acre => value / 4046.8564224,
-            AreaUnit::Hectare => value / 10000.0,
+        Unit::FuelEconomy(economy) => match economy {
+            FuelEconomyUnit::LitersPer100Km => "liters per 100 kilometers".to_string(),
+            FuelEconomyUnit::KilometersPerLiter => "kilometers per liter".to_string(),
+            FuelEconomyUnit::MilesPerGallonUs => "miles per US gallon".to_string(),
+            FuelEconomyUnit::MilesPerGallonUk => "miles per imperial gallon".to_string(),
         }
 
-        Unit::Volume(vol) => match vol {
-            VolUnit::Liter(i) => {
-                let scale = power_of(-i);
-                value * scale
-            }
-            VolUnit::Meter3(i) => {
-                let scale = power_of(-i*3);
-                value * scale * 0.001
-            }
-            VolUnit::TeaSpoon => value / 0.005,
-            VolUnit::TableSpoon => value / 0.015,
-            VolUnit::Cup => value / 0.2365882365,
-            VolUnit::Pint => value / 0.473176473,
-            VolUnit::Gallon => value / 3.785411784,
+        Unit::Density(density) => match density {
+            DensityUnit::MassPerVolume(mass, vol) => per_name(Unit::Mass(mass), Unit::Volume(vol)),
+            DensityUnit::SpecificGravity => "specific gravity".to_string(),
         }
 
-        Unit::Mass(mass) => match mass {
-            MassUnit::Gram(i) => {
-                let scale = power_of(-i);
-                value * scale
+        Unit::Concentration(concentration) => match concentration {
+            ConcentrationUnit::MolePerLiter(i) => {
+                let prefix = scale_to_string(i).unwrap_or_default();
+                format!("{}moles per liter", prefix)
             }
-            MassUnit::Ounce => value / 28.34952,
-            MassUnit::Pound => value / 453.59237,
-            MassUnit::Stone => value / (453.59237 * 14.0),
+            ConcentrationUnit::PartsPerMillion => "parts per million".to_string(),
+            ConcentrationUnit::PartsPerBillion => "parts per billion".to_string(),
+            ConcentrationUnit::PercentWeightPerVolume => "percent weight per volume".to_string(),
         }
-    }
-}
 
-pub fn convert(value: f32, a: Unit, b: Unit) -> f32 {
-    convert_from_standard(
-        convert_to_standard(value, a),
-        b
-    )
-}
+        Unit::FlowRate(FlowRateUnit::VolumePerTime(vol, time)) => per_name(Unit::Volume(vol), Unit::Time(time)),
 
-pub fn convert_and_print_to(value: f32, a: Unit, b: Unit) {
-    let str_a = unit_to_string(a);
-    println!("{value} {str_a} equals to...");
+        Unit::MassFlow(MassFlowUnit::MassPerTime(mass, time)) => per_name(Unit::Mass(mass), Unit::Time(time)),
 
-    let str_b = unit_to_string(b);
-    let converted = convert(value, a, b);
-    println!("\t {converted} {str_b}");
-}
+        Unit::Charge(charge) => match charge {
+            ChargeUnit::Coulomb(i) => {
+                let prefix = scale_to_string(i).unwrap_or_default();
+                format!("{}coulombs", prefix)
+            }
+            ChargeUnit::AmpereHour(i) => {
+                let prefix = scale_to_string(i).unwrap_or_default();
+                format!("{}ampere-hours", prefix)
+            }
+        }
 
-fn fetch_all_units(unit: Unit) -> Vec<Unit> {
-    match unit {
-        Unit::Temperature(_) => vec![
-            Unit::Temperature(TempUnit::Kelvin),
-            Unit::Temperature(TempUnit::Celsius),
-            Unit::Temperature(TempUnit::Fahrenheit)
-        ],
-        Unit::Length(_) => vec![
-            Unit::Length(LengthUnit::Meter(-3)),
-            Unit::Length(LengthUnit::Meter(-2)),
-            Unit::Length(LengthUnit::Meter(0)),
-            Unit::Length(LengthUnit::Meter(3)),
+        Unit::Current(CurrentUnit::Ampere(i)) => {
+            let prefix = scale_to_string(i).unwrap_or_default();
+            format!("{}amperes", prefix)
+        }
+
+        Unit::Voltage(VoltageUnit::Volt(i)) => {
+            let prefix = scale_to_string(i).unwrap_or_default();
+            format!("{}volts", prefix)
+        }
+
+        Unit::Resistance(ResistanceUnit::Ohm(i)) => {
+            let prefix = scale_to_string(i).unwrap_or_default();
+            format!("{}ohms", prefix)
+        }
+
+        Unit::Conductance(ConductanceUnit::Siemens(i)) => {
+            let prefix = scale_to_string(i).unwrap_or_default();
+            format!("{}siemens", prefix)
+        }
+
+        Unit::Capacitance(CapacitanceUnit::Farad(i)) => {
+            let prefix = scale_to_string(i).unwrap_or_default();
+            format!("{}farads", prefix)
+        }
+
+        Unit::Inductance(InductanceUnit::Henry(i)) => {
+            let prefix = scale_to_string(i).unwrap_or_default();
+            format!("{}henries", prefix)
+        }
+
+        Unit::MagneticFluxDensity(flux_density) => match flux_density {
+            MagneticFluxDensityUnit::Tesla(i) => {
+                let prefix = scale_to_string(i).unwrap_or_default();
+                format!("{}teslas", prefix)
+            }
+            MagneticFluxDensityUnit::Gauss => "gauss".to_string(),
+        }
+
+        Unit::MagneticFlux(MagneticFluxUnit::Weber(i)) => {
+            let prefix = scale_to_string(i).unwrap_or_default();
+            format!("{}webers", prefix)
+        }
+
+        Unit::LuminousIntensity(LuminousIntensityUnit::Candela(i)) => {
+            let prefix = scale_to_string(i).unwrap_or_default();
+            format!("{}candelas", prefix)
+        }
+
+        Unit::LuminousFlux(LuminousFluxUnit::Lumen(i)) => {
+            let prefix = scale_to_string(i).unwrap_or_default();
+            format!("{}lumens", prefix)
+        }
+
+        Unit::Illuminance(unit) => match unit {
+            IlluminanceUnit::Lux(i) => {
+                let prefix = scale_to_string(i).unwrap_or_default();
+                format!("{}lux", prefix)
+            }
+            IlluminanceUnit::FootCandle => "foot-candles".to_string(),
+            IlluminanceUnit::Phot => "phots".to_string(),
+        }
+
+        Unit::Luminance(luminance) => match luminance {
+            LuminanceUnit::Nit => "nits".to_string(),
+            LuminanceUnit::FootLambert => "foot-lamberts".to_string(),
+        }
+
+        Unit::Radioactivity(activity) => match activity {
+            RadioactivityUnit::Becquerel(i) => {
+                let prefix = scale_to_string(i).unwrap_or_default();
+                format!("{}becquerels", prefix)
+            }
+            RadioactivityUnit::Curie(i) => {
+                let prefix = scale_to_string(i).unwrap_or_default();
+                format!("{}curies", prefix)
+            }
+            RadioactivityUnit::Rutherford => "rutherfords".to_string(),
+            RadioactivityUnit::DisintegrationPerMinute => "disintegrations per minute".to_string(),
+        }
+
+        Unit::AbsorbedDose(dose) => match dose {
+            AbsorbedDoseUnit::Gray(i) => {
+                let prefix = scale_to_string(i).unwrap_or_default();
+                format!("{}grays", prefix)
+            }
+            AbsorbedDoseUnit::Rad(i) => {
+                let prefix = scale_to_string(i).unwrap_or_default();
+                format!("{}rads", prefix)
+            }
+        }
+
+        Unit::EquivalentDose(dose) => match dose {
+            EquivalentDoseUnit::Sievert(i) => {
+                let prefix = scale_to_string(i).unwrap_or_default();
+                format!("{}sieverts", prefix)
+            }
+            EquivalentDoseUnit::Rem(i) => {
+                let prefix = scale_to_string(i).unwrap_or_default();
+                format!("{}rems", prefix)
+            }
+        }
+
+        Unit::Acceleration(acceleration) => match acceleration {
+            AccelerationUnit::MeterPerSecond2(i) => {
+                let prefix = scale_to_string(i).unwrap_or_default();
+                format!("{}meters per second squared", prefix)
+            }
+            AccelerationUnit::FootPerSecond2 => "feet per second squared".to_string(),
+            AccelerationUnit::StandardGravity => "standard gravities".to_string(),
+            AccelerationUnit::Gal(i) => {
+                let prefix = scale_to_string(i).unwrap_or_default();
+                format!("{}gals", prefix)
+            }
+        }
+
+        Unit::Amount(amount) => match amount {
+            AmountUnit::Mole(i) => {
+                let prefix = scale_to_string(i).unwrap_or_default();
+                format!("{}moles", prefix)
+            }
+            AmountUnit::Particle => "particles".to_string(),
+        }
+    }
+}
+
+fn per_symbol(numerator: Unit, denominator: Unit) -> String {
+    format!("{}/{}", unit_to_symbol(numerator), unit_to_symbol(denominator))
+}
+
+/// `symbol` with the system appended unless it is the unit's usual one,
+/// e.g. "gal" for the US gallon but "gal_UK" for the imperial one.
+fn system_symbol(symbol: &str, system: VolumeSystem, usual: VolumeSystem) -> String {
+    let suffix = match system {
+        _ if system == usual => return symbol.to_string(),
+        VolumeSystem::UsLiquid => "US",
+        VolumeSystem::UsDry => "US_dry",
+        VolumeSystem::Imperial => "UK",
+        VolumeSystem::Metric => "metric",
+        VolumeSystem::Australian => "AU",
+    };
+    format!("{symbol}_{suffix}")
+}
+
+fn unit_to_symbol(unit: Unit) -> String {
+    match unit {
+        Unit::Temperature(temp) => match temp {
+            TempUnit::Kelvin => "K".to_string(),
+            TempUnit::Celsius => "C".to_string(),
+            TempUnit::Fahrenheit => "F".to_string(),
+        }
+
+        Unit::Length(length) => match length {
+            LengthUnit::Meter(i) => {
+                let prefix = scale_to_symbol(i).unwrap_or_default();
+                format!("{}m", prefix)
+            }
+            LengthUnit::Inch => "in".to_string(),
+            LengthUnit::Feet => "ft".to_string(),
+            LengthUnit::Yard => "yd".to_string(),
+            LengthUnit::Mile => "mi".to_string(),
+            LengthUnit::Mil => "mil".to_string(),
+            LengthUnit::NauticalMile => "nmi".to_string(),
+            LengthUnit::Fathom => "ftm".to_string(),
+            LengthUnit::Chain => "ch".to_string(),
+            LengthUnit::Link => "li".to_string(),
+            LengthUnit::Rod => "rd".to_string(),
+            LengthUnit::Furlong => "fur".to_string(),
+            LengthUnit::League => "lea".to_string(),
+            LengthUnit::SurveyFoot => "ft_US".to_string(),
+            LengthUnit::SurveyMile => "mi_US".to_string(),
+            LengthUnit::Angstrom => "Å".to_string(),
+            LengthUnit::AstronomicalUnit => "au".to_string(),
+            LengthUnit::LightYear => "ly".to_string(),
+            LengthUnit::Parsec => "pc".to_string(),
+            // "pt" alone is the pint
+            LengthUnit::Point => "pt_DTP".to_string(),
+            LengthUnit::Pica => "pica".to_string(),
+            LengthUnit::DidotPoint => "dd".to_string(),
+            LengthUnit::Cicero => "cicero".to_string(),
+            LengthUnit::Twip => "twip".to_string(),
+            LengthUnit::Em => "em".to_string(),
+            LengthUnit::Pixel => "px".to_string(),
+        }
+
+        Unit::Area(area) => match area {
+            AreaUnit::Meter2(i) => {
+                let prefix = scale_to_symbol(i).unwrap_or_default();
+                format!("{}m2", prefix)
+            }
+            AreaUnit::Inch2 => "in2".to_string(),
+            AreaUnit::Feet2 => "ft2".to_string(),
+            AreaUnit::Yard2 => "yd2".to_string(),
+            AreaUnit::Mile2 => "mi2".to_string(),
+            AreaUnit::Acre => "ac".to_string(),
+            AreaUnit::Hectare => "ha".to_string(),
+        }
+
+        Unit::Volume(vol) => match vol {
+            VolUnit::Liter(i) => {
+                let prefix = scale_to_symbol(i).unwrap_or_default();
+                format!("{}L", prefix)
+            }
+            VolUnit::Meter3(i) => {
+                let prefix = scale_to_symbol(i).unwrap_or_default();
+                format!("{}m3", prefix)
+            }
+            VolUnit::TeaSpoon(system) => system_symbol("tsp", system, VolumeSystem::Metric),
+            VolUnit::TableSpoon(system) => system_symbol("tbsp", system, VolumeSystem::Metric),
+            VolUnit::FluidOunce(system) => system_symbol("fl oz", system, VolumeSystem::UsLiquid),
+            VolUnit::Gill(system) => system_symbol("gi", system, VolumeSystem::UsLiquid),
+            VolUnit::Cup(system) => system_symbol("cup", system, VolumeSystem::UsLiquid),
+            VolUnit::Pint(system) => system_symbol("pt", system, VolumeSystem::UsLiquid),
+            VolUnit::Quart(system) => system_symbol("qt", system, VolumeSystem::UsLiquid),
+            VolUnit::Gallon(system) => system_symbol("gal", system, VolumeSystem::UsLiquid),
+            VolUnit::Peck(system) => system_symbol("pk", system, VolumeSystem::UsDry),
+            VolUnit::Bushel(system) => system_symbol("bu", system, VolumeSystem::UsDry),
+            VolUnit::Inch3 => "in3".to_string(),
+            VolUnit::Feet3 => "ft3".to_string(),
+            VolUnit::Yard3 => "yd3".to_string(),
+            VolUnit::Barrel => "bbl".to_string(),
+        }
+
+        Unit::Mass(mass) => match mass {
+            MassUnit::Gram(i) => {
+                let prefix = scale_to_symbol(i).unwrap_or_default();
+                format!("{}g", prefix)
+            }
+            MassUnit::Ounce => "oz".to_string(),
+            MassUnit::Pound => "lb".to_string(),
+            MassUnit::Stone => "st".to_string(),
+            MassUnit::ShortHundredweight => "cwt_US".to_string(),
+            MassUnit::LongHundredweight => "cwt_UK".to_string(),
+            MassUnit::ShortTon => "ton_US".to_string(),
+            MassUnit::LongTon => "ton_UK".to_string(),
+            MassUnit::Tonne => "t".to_string(),
+            MassUnit::Dram => "dr".to_string(),
+            MassUnit::Grain => "gr".to_string(),
+            MassUnit::TroyOunce => "ozt".to_string(),
+            MassUnit::TroyPound => "lbt".to_string(),
+            MassUnit::Pennyweight => "dwt".to_string(),
+            MassUnit::ApothecaryDram => "dr_ap".to_string(),
+            MassUnit::Scruple => "s_ap".to_string(),
+            MassUnit::Carat => "ct".to_string(),
+            MassUnit::Slug => "slug".to_string(),
+        }
+
+        Unit::Speed(speed) => match speed {
+            SpeedUnit::MeterPerSecond(i) => {
+                let prefix = scale_to_symbol(i).unwrap_or_default();
+                format!("{}m/s", prefix)
+            }
+            SpeedUnit::KilometerPerHour => "km/h".to_string(),
+            SpeedUnit::MilePerHour => "mph".to_string(),
+            SpeedUnit::FootPerSecond => "ft/s".to_string(),
+            SpeedUnit::Knot => "kn".to_string(),
+            SpeedUnit::Mach => "Ma".to_string(),
+            SpeedUnit::SpeedOfLight => "c0".to_string(),
+        }
+
+        Unit::Time(time) => match time {
+            TimeUnit::Second(i) => {
+                let prefix = scale_to_symbol(i).unwrap_or_default();
+                format!("{}s", prefix)
+            }
+            TimeUnit::Minute => "min".to_string(),
+            TimeUnit::Hour => "h".to_string(),
+            TimeUnit::Day => "d".to_string(),
+            TimeUnit::Week => "wk".to_string(),
+            TimeUnit::JulianYear => "yr".to_string(),
+        }
+
+        Unit::Pressure(pressure) => match pressure {
+            PressureUnit::Pascal(i) => {
+                let prefix = scale_to_symbol(i).unwrap_or_default();
+                format!("{}Pa", prefix)
+            }
+            PressureUnit::Bar(i) => {
+                let prefix = scale_to_symbol(i).unwrap_or_default();
+                format!("{}bar", prefix)
+            }
+            PressureUnit::Atmosphere => "atm".to_string(),
+            PressureUnit::Psi => "psi".to_string(),
+            PressureUnit::Psig => "psig".to_string(),
+            PressureUnit::MillimeterOfMercury => "mmHg".to_string(),
+            PressureUnit::InchOfMercury => "inHg".to_string(),
+            PressureUnit::Torr => "Torr".to_string(),
+            PressureUnit::KilogramForcePerCm2 => "kgf/cm2".to_string(),
+        }
+
+        Unit::Energy(energy) => match energy {
+            EnergyUnit::Joule(i) => {
+                let prefix = scale_to_symbol(i).unwrap_or_default();
+                format!("{}J", prefix)
+            }
+            EnergyUnit::WattHour(i) => {
+                let prefix = scale_to_symbol(i).unwrap_or_default();
+                format!("{}Wh", prefix)
+            }
+            EnergyUnit::Calorie(i) => {
+                let prefix = scale_to_symbol(i).unwrap_or_default();
+                format!("{}cal", prefix)
+            }
+            EnergyUnit::Electronvolt(i) => {
+                let prefix = scale_to_symbol(i).unwrap_or_default();
+                format!("{}eV", prefix)
+            }
+            EnergyUnit::CalorieIt => "cal_IT".to_string(),
+            EnergyUnit::KilocalorieIt => "kcal_IT".to_string(),
+            EnergyUnit::FoodCalorie => "Cal".to_string(),
+            EnergyUnit::BtuIt => "BTU".to_string(),
+            EnergyUnit::BtuThermochemical => "BTU_th".to_string(),
+            EnergyUnit::Erg => "erg".to_string(),
+            EnergyUnit::Therm => "thm".to_string(),
+            EnergyUnit::FootPound => "ft·lbf".to_string(),
+        }
+
+        Unit::Power(power) => match power {
+            PowerUnit::Watt(i) => {
+                let prefix = scale_to_symbol(i).unwrap_or_default();
+                format!("{}W", prefix)
+            }
+            PowerUnit::HorsepowerMechanical => "hp".to_string(),
+            PowerUnit::HorsepowerMetric => "hp(M)".to_string(),
+            PowerUnit::HorsepowerElectrical => "hp(E)".to_string(),
+            PowerUnit::HorsepowerBoiler => "hp(S)".to_string(),
+            PowerUnit::BtuPerHour => "BTU/h".to_string(),
+            PowerUnit::TonOfRefrigeration => "TR".to_string(),
+            PowerUnit::ErgPerSecond => "erg/s".to_string(),
+            PowerUnit::DecibelMilliwatt => "dBm".to_string(),
+            PowerUnit::DecibelWatt => "dBW".to_string(),
+        }
+
+        Unit::Data(data) => match data {
+            DataUnit::Bit(i) => {
+                let prefix = scale_to_symbol(i).unwrap_or_default();
+                format!("{}bit", prefix)
+            }
+            DataUnit::Byte(i) => {
+                let prefix = scale_to_symbol(i).unwrap_or_default();
+                format!("{}B", prefix)
+            }
+            DataUnit::BinaryBit(i) => {
+                let prefix = binary_scale_to_symbol(i).unwrap_or_default();
+                format!("{}bit", prefix)
+            }
+            DataUnit::BinaryByte(i) => {
+                let prefix = binary_scale_to_symbol(i).unwrap_or_default();
+                format!("{}B", prefix)
+            }
+            DataUnit::Nibble => "nibble".to_string(),
+        }
+
+        Unit::DataRate(rate) => match rate {
+            DataRateUnit::BitPerSecond(i) => {
+                let prefix = scale_to_symbol(i).unwrap_or_default();
+                format!("{}bit/s", prefix)
+            }
+            DataRateUnit::BytePerSecond(i) => {
+                let prefix = scale_to_symbol(i).unwrap_or_default();
+                format!("{}B/s", prefix)
+            }
+            DataRateUnit::BinaryBitPerSecond(i) => {
+                let prefix = binary_scale_to_symbol(i).unwrap_or_default();
+                format!("{}bit/s", prefix)
+            }
+            DataRateUnit::BinaryBytePerSecond(i) => {
+                let prefix = binary_scale_to_symbol(i).unwrap_or_default();
+                format!("{}B/s", prefix)
+            }
+        }
+
+        Unit::Angle(angle) => match angle {
+            AngleUnit::Radian(i) => {
+                let prefix = scale_to_symbol(i).unwrap_or_default();
+                format!("{}rad", prefix)
+            }
+            AngleUnit::Degree => "deg".to_string(),
+            AngleUnit::Arcminute => "arcmin".to_string(),
+            AngleUnit::Arcsecond => "arcsec".to_string(),
+            AngleUnit::Gradian => "gon".to_string(),
+            AngleUnit::Turn => "turn".to_string(),
+            AngleUnit::MilNato => "mil_NATO".to_string(),
+            AngleUnit::MilWarsawPact => "mil_WP".to_string(),
+            AngleUnit::MilSwedish => "mil_SE".to_string(),
+        }
+
+        Unit::SolidAngle(solid) => match solid {
+            SolidAngleUnit::Steradian(i) => {
+                let prefix = scale_to_symbol(i).unwrap_or_default();
+                format!("{}sr", prefix)
+            }
+            SolidAngleUnit::SquareDegree => "deg2".to_string(),
+        }
+
+        Unit::Force(force) => match force {
+            ForceUnit::Newton(i) => {
+                let prefix = scale_to_symbol(i).unwrap_or_default();
+                format!("{}N", prefix)
+            }
+            ForceUnit::Dyne => "dyn".to_string(),
+            ForceUnit::KilogramForce => "kgf".to_string(),
+            ForceUnit::PoundForce => "lbf".to_string(),
+            ForceUnit::Poundal => "pdl".to_string(),
+            ForceUnit::Kip => "kip".to_string(),
+        }
+
+        Unit::Torque(torque) => match torque {
+            TorqueUnit::NewtonMeter(i) => {
+                let prefix = scale_to_symbol(i).unwrap_or_default();
+                format!("{}N·m", prefix)
+            }
+            TorqueUnit::PoundForceFoot => "lbf·ft".to_string(),
+            TorqueUnit::PoundForceInch => "lbf·in".to_string(),
+            TorqueUnit::KilogramForceMeter => "kgf·m".to_string(),
+        }
+
+        Unit::Frequency(frequency) => match frequency {
+            FrequencyUnit::Hertz(i) => {
+                let prefix = scale_to_symbol(i).unwrap_or_default();
+                format!("{}Hz", prefix)
+            }
+            FrequencyUnit::RevolutionPerMinute => "rpm".to_string(),
+            FrequencyUnit::RadianPerSecond => "rad/s".to_string(),
+            FrequencyUnit::CyclePerMinute => "cpm".to_string(),
+            FrequencyUnit::BeatPerMinute => "bpm".to_string(),
+        }
+
+        Unit::FuelEconomy(economy) => match economy {
+            FuelEconomyUnit::LitersPer100Km => "L/100km".to_string(),
+            FuelEconomyUnit::KilometersPerLiter => "km/L".to_string(),
+            FuelEconomyUnit::MilesPerGallonUs => "mpg_US".to_string(),
+            FuelEconomyUnit::MilesPerGallonUk => "mpg_UK".to_string(),
+        }
+
+        Unit::Density(density) => match density {
+            DensityUnit::MassPerVolume(mass, vol) => per_symbol(Unit::Mass(mass), Unit::Volume(vol)),
+            DensityUnit::SpecificGravity => "SG".to_string(),
+        }
+
+        Unit::Concentration(concentration) => match concentration {
+            ConcentrationUnit::MolePerLiter(i) => {
+                let prefix = scale_to_symbol(i).unwrap_or_default();
+                format!("{}mol/L", prefix)
+            }
+            ConcentrationUnit::PartsPerMillion => "ppm".to_string(),
+            ConcentrationUnit::PartsPerBillion => "ppb".to_string(),
+            ConcentrationUnit::PercentWeightPerVolume => "%w/v".to_string(),
+        }
+
+        Unit::FlowRate(FlowRateUnit::VolumePerTime(vol, time)) => per_symbol(Unit::Volume(vol), Unit::Time(time)),
+
+        Unit::MassFlow(MassFlowUnit::MassPerTime(mass, time)) => per_symbol(Unit::Mass(mass), Unit::Time(time)),
+
+        Unit::Charge(charge) => match charge {
+            // A bare "C" is degrees Celsius
+            ChargeUnit::Coulomb(0) => "C_charge".to_string(),
+            ChargeUnit::Coulomb(i) => {
+                let prefix = scale_to_symbol(i).unwrap_or_default();
+                format!("{}C", prefix)
+            }
+            ChargeUnit::AmpereHour(i) => {
+                let prefix = scale_to_symbol(i).unwrap_or_default();
+                format!("{}Ah", prefix)
+            }
+        }
+
+        Unit::Current(CurrentUnit::Ampere(i)) => {
+            let prefix = scale_to_symbol(i).unwrap_or_default();
+            format!("{}A", prefix)
+        }
+
+        Unit::Voltage(VoltageUnit::Volt(i)) => {
+            let prefix = scale_to_symbol(i).unwrap_or_default();
+            format!("{}V", prefix)
+        }
+
+        Unit::Resistance(ResistanceUnit::Ohm(i)) => {
+            let prefix = scale_to_symbol(i).unwrap_or_default();
+            format!("{}Ω", prefix)
+        }
+
+        Unit::Conductance(ConductanceUnit::Siemens(i)) => {
+            let prefix = scale_to_symbol(i).unwrap_or_default();
+            format!("{}S", prefix)
+        }
+
+        // A bare "F" is degrees Fahrenheit
+        Unit::Capacitance(CapacitanceUnit::Farad(0)) => "F_capacitance".to_string(),
+        Unit::Capacitance(CapacitanceUnit::Farad(i)) => {
+            let prefix = scale_to_symbol(i).unwrap_or_default();
+            format!("{}F", prefix)
+        }
+
+        Unit::Inductance(InductanceUnit::Henry(i)) => {
+            let prefix = scale_to_symbol(i).unwrap_or_default();
+            format!("{}H", prefix)
+        }
+
+        Unit::MagneticFluxDensity(flux_density) => match flux_density {
+            MagneticFluxDensityUnit::Tesla(i) => {
+                let prefix = scale_to_symbol(i).unwrap_or_default();
+                format!("{}T", prefix)
+            }
+            MagneticFluxDensityUnit::Gauss => "G".to_string(),
+        }
+
+        Unit::MagneticFlux(MagneticFluxUnit::Weber(i)) => {
+            let prefix = scale_to_symbol(i).unwrap_or_default();
+            format!("{}Wb", prefix)
+        }
+
+        Unit::LuminousIntensity(LuminousIntensityUnit::Candela(i)) => {
+            let prefix = scale_to_symbol(i).unwrap_or_default();
+            format!("{}cd", prefix)
+        }
+
+        Unit::LuminousFlux(LuminousFluxUnit::Lumen(i)) => {
+            let prefix = scale_to_symbol(i).unwrap_or_default();
+            format!("{}lm", prefix)
+        }
+
+        Unit::Illuminance(unit) => match unit {
+            IlluminanceUnit::Lux(i) => {
+                let prefix = scale_to_symbol(i).unwrap_or_default();
+                format!("{}lx", prefix)
+            }
+            IlluminanceUnit::FootCandle => "fc".to_string(),
+            IlluminanceUnit::Phot => "ph".to_string(),
+        }
+
+        Unit::Luminance(luminance) => match luminance {
+            LuminanceUnit::Nit => "cd/m2".to_string(),
+            LuminanceUnit::FootLambert => "ftL".to_string(),
+        }
+
+        Unit::Radioactivity(activity) => match activity {
+            RadioactivityUnit::Becquerel(i) => {
+                let prefix = scale_to_symbol(i).unwrap_or_default();
+                format!("{}Bq", prefix)
+            }
+            RadioactivityUnit::Curie(i) => {
+                let prefix = scale_to_symbol(i).unwrap_or_default();
+                format!("{}Ci", prefix)
+            }
+            RadioactivityUnit::Rutherford => "Rd".to_string(),
+            RadioactivityUnit::DisintegrationPerMinute => "dpm".to_string(),
+        }
+
+        Unit::AbsorbedDose(dose) => match dose {
+            AbsorbedDoseUnit::Gray(i) => {
+                let prefix = scale_to_symbol(i).unwrap_or_default();
+                format!("{}Gy", prefix)
+            }
+            AbsorbedDoseUnit::Rad(i) => {
+                let prefix = scale_to_symbol(i).unwrap_or_default();
+                format!("{}rad_dose", prefix)
+            }
+        }
+
+        Unit::EquivalentDose(dose) => match dose {
+            EquivalentDoseUnit::Sievert(i) => {
+                let prefix = scale_to_symbol(i).unwrap_or_default();
+                format!("{}Sv", prefix)
+            }
+            EquivalentDoseUnit::Rem(i) => {
+                let prefix = scale_to_symbol(i).unwrap_or_default();
+                format!("{}rem", prefix)
+            }
+        }
+
+        Unit::Acceleration(acceleration) => match acceleration {
+            AccelerationUnit::MeterPerSecond2(i) => {
+                let prefix = scale_to_symbol(i).unwrap_or_default();
+                format!("{}m/s2", prefix)
+            }
+            AccelerationUnit::FootPerSecond2 => "ft/s2".to_string(),
+            AccelerationUnit::StandardGravity => "g0".to_string(),
+            AccelerationUnit::Gal(i) => {
+                let prefix = scale_to_symbol(i).unwrap_or_default();
+                format!("{}Gal", prefix)
+            }
+        }
+
+        Unit::Amount(amount) => match amount {
+            AmountUnit::Mole(i) => {
+                let prefix = scale_to_symbol(i).unwrap_or_default();
+                format!("{}mol", prefix)
+            }
+            AmountUnit::Particle => "particles".to_string(),
+        }
+    }
+}
+
+/// `{}` prints the long name ("kilometers"), `{:#}` the symbol ("km").
+/// Both forms parse back into the same unit.
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            f.pad(&unit_to_symbol(*self))
+        } else {
+            f.pad(&unit_to_string(*self))
+        }
+    }
+}
+
+/// Conversions, formatting and parsing shared by every per-dimension unit
+/// enum, all delegating to their [`Unit`] counterpart.
+macro_rules! impl_dimension_unit {
+    ($($variant:ident($unit:ident)),* $(,)?) => {$(
+        impl From<$unit> for Unit {
+            fn from(unit: $unit) -> Unit {
+                Unit::$variant(unit)
+            }
+        }
+
+        impl fmt::Display for $unit {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::Display::fmt(&Unit::$variant(*self), f)
+            }
+        }
+
+        impl std::str::FromStr for $unit {
+            type Err = ParseUnitError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match Unit::parse_as(s, Dimension::$variant)? {
+                    Unit::$variant(unit) => Ok(unit),
+                    _ => Err(ParseUnitError::new(s)),
+                }
+            }
+        }
+    )*};
+}
+
+impl_dimension_unit!(
+    Temperature(TempUnit),
+    Length(LengthUnit),
+    Area(AreaUnit),
+    Volume(VolUnit),
+    Mass(MassUnit),
+    Speed(SpeedUnit),
+    Time(TimeUnit),
+    Pressure(PressureUnit),
+    Energy(EnergyUnit),
+    Power(PowerUnit),
+    Data(DataUnit),
+    DataRate(DataRateUnit),
+    Angle(AngleUnit),
+    SolidAngle(SolidAngleUnit),
+    Force(ForceUnit),
+    Torque(TorqueUnit),
+    Frequency(FrequencyUnit),
+    FuelEconomy(FuelEconomyUnit),
+    Density(DensityUnit),
+    Concentration(ConcentrationUnit),
+    FlowRate(FlowRateUnit),
+    MassFlow(MassFlowUnit),
+    Charge(ChargeUnit),
+    Current(CurrentUnit),
+    Voltage(VoltageUnit),
+    Resistance(ResistanceUnit),
+    Conductance(ConductanceUnit),
+    Capacitance(CapacitanceUnit),
+    Inductance(InductanceUnit),
+    MagneticFluxDensity(MagneticFluxDensityUnit),
+    MagneticFlux(MagneticFluxUnit),
+    LuminousIntensity(LuminousIntensityUnit),
+    LuminousFlux(LuminousFluxUnit),
+    Illuminance(IlluminanceUnit),
+    Luminance(LuminanceUnit),
+    Radioactivity(RadioactivityUnit),
+    AbsorbedDose(AbsorbedDoseUnit),
+    EquivalentDose(EquivalentDoseUnit),
+    Acceleration(AccelerationUnit),
+    Amount(AmountUnit),
+);
+
+/// An affine map to the standard unit of a dimension:
+/// `standard = value * factor * 10^exp * π^pi + offset`.
+///
+/// Factors are kept as exact fractions so that [`convert_exact`] can
+/// reproduce definitions such as "1 inch = 2.54 cm" without rounding.
+/// Powers of π cancel between e.g. degrees and arcminutes, so those stay
+/// exact too.
+#[derive(Copy, Clone, Debug)]
+struct Linear {
+    factor: Ratio,
+    exp: i32,
+    pi: i32,
+    offset: Ratio,
+}
+
+impl Linear {
+    fn ratio(num: i128, den: i128) -> Linear {
+        Linear { factor: Ratio::new(num, den), exp: 0, pi: 0, offset: Ratio::ZERO }
+    }
+
+    fn power_of_ten(exp: i32) -> Linear {
+        Linear { factor: Ratio::ONE, exp, pi: 0, offset: Ratio::ZERO }
+    }
+
+    /// `num * 2^exp`, for units with a binary prefix.
+    fn binary(num: i128, exp: u8) -> Linear {
+        // Only kibi to yobi are named and `check_units` refuses the rest,
+        // but keep unvalidated conversions from overflowing the shift
+        Linear::ratio(num << exp.min(120), 1)
+    }
+
+    /// `num / den * π`, for angles measured against the radian.
+    fn pi_ratio(num: i128, den: i128) -> Linear {
+        Linear { pi: 1, ..Linear::ratio(num, den) }
+    }
+
+    fn with_offset(self, num: i128, den: i128) -> Linear {
+        Linear { offset: Ratio::new(num, den), ..self }
+    }
+
+    fn factor_f64(self) -> f64 {
+        self.factor.to_f64() * 10f64.powi(self.exp) * std::f64::consts::PI.powi(self.pi)
+    }
+
+    /// The product of two maps without offsets, in exact arithmetic.
+    fn times(self, other: Linear) -> Option<Linear> {
+        Some(Linear {
+            factor: self.factor.checked_mul(other.factor)?,
+            exp: self.exp + other.exp,
+            pi: self.pi + other.pi,
+            offset: Ratio::ZERO,
+        })
+    }
+
+    /// The map going straight from this unit to `other`, in exact arithmetic.
+    fn then_inverse(self, other: Linear) -> Option<Linear> {
+        let factor = self.factor.checked_div(other.factor)?;
+        let offset = if self.offset == other.offset {
+            Ratio::ZERO
+        } else if other.pi != 0 {
+            return None;
+        } else {
+            self.offset
+                .checked_sub(other.offset)?
+                .checked_div(other.factor)?
+                .checked_scale10(-other.exp)?
+        };
+        Some(Linear { factor, exp: self.exp - other.exp, pi: self.pi - other.pi, offset })
+    }
+}
+
+/// The map for a unit such as kg/m³, from the maps of its two parts.
+fn per_conversion(numerator: Unit, denominator: Unit, context: &ConversionContext) -> Linear {
+    let (Conversion::Linear(numerator), Conversion::Linear(denominator)) =
+        (conversion(numerator, context), conversion(denominator, context))
+    else {
+        unreachable!("masses, volumes and times are linear");
+    };
+    numerator.then_inverse(denominator).expect("mass, volume and time factors are small fractions")
+}
+
+/// How a unit maps to the standard unit of its dimension.
+#[derive(Copy, Clone, Debug)]
+enum Conversion {
+    Linear(Linear),
+    /// Decibels relative to `reference` standard units, a power ratio:
+    /// `standard = reference * 10^(value / 10)`.
+    Decibel(Ratio),
+    /// Inversely proportional to the standard unit, as litres per 100 km are
+    /// to kilometers per liter: `standard = k / value`.
+    Reciprocal(Ratio),
+}
+
+/// The size of a customary volume in liters, or `None` if its system has no
+/// such unit. US and imperial units are fractions of their gallons.
+fn customary_volume(volume: VolUnit) -> Option<Linear> {
+    use VolumeSystem::*;
+
+    let (system, gallons) = match volume {
+        VolUnit::TeaSpoon(Metric) => return Some(Linear::ratio(5, 1000)),
+        VolUnit::TableSpoon(Metric) => return Some(Linear::ratio(15, 1000)),
+        VolUnit::TableSpoon(Australian) => return Some(Linear::ratio(20, 1000)),
+        VolUnit::Cup(Metric) => return Some(Linear::ratio(250, 1000)),
+
+        // The imperial tablespoon is 5/8 of an imperial fluid ounce, which
+        // makes it the same fraction of a gallon as the US one
+        VolUnit::TeaSpoon(system @ (UsLiquid | Imperial)) => (system, Ratio::new(1, 768)),
+        VolUnit::TableSpoon(system @ (UsLiquid | Imperial)) => (system, Ratio::new(1, 256)),
+        VolUnit::FluidOunce(UsLiquid) => (UsLiquid, Ratio::new(1, 128)),
+        VolUnit::FluidOunce(Imperial) => (Imperial, Ratio::new(1, 160)),
+        VolUnit::Gill(system @ (UsLiquid | Imperial)) => (system, Ratio::new(1, 32)),
+        VolUnit::Cup(system @ (UsLiquid | Imperial)) => (system, Ratio::new(1, 16)),
+        VolUnit::Pint(system @ (UsLiquid | UsDry | Imperial)) => (system, Ratio::new(1, 8)),
+        VolUnit::Quart(system @ (UsLiquid | UsDry | Imperial)) => (system, Ratio::new(1, 4)),
+        VolUnit::Gallon(system @ (UsLiquid | UsDry | Imperial)) => (system, Ratio::ONE),
+        VolUnit::Peck(system @ (UsDry | Imperial)) => (system, Ratio::from(2)),
+        VolUnit::Bushel(system @ (UsDry | Imperial)) => (system, Ratio::from(8)),
+        _ => return None,
+    };
+    let gallon = match system {
+        // 231 and 268.8025 cubic inches
+        UsLiquid => Ratio::new(3_785_411_784, 1_000_000_000),
+        UsDry => Ratio::new(440_488_377_086, 100_000_000_000),
+        Imperial => Ratio::new(454_609, 100_000),
+        Metric | Australian => return None,
+    };
+    Some(Linear { factor: gallon * gallons, ..Linear::ratio(1, 1) })
+}
+
+fn conversion(unit: Unit, context: &ConversionContext) -> Conversion {
+    let linear = match unit {
+        // Standard: kelvin
+        Unit::Temperature(temp) => match temp {
+            TempUnit::Kelvin => Linear::ratio(1, 1),
+            TempUnit::Celsius => Linear::ratio(1, 1).with_offset(27315, 100),
+            TempUnit::Fahrenheit => Linear::ratio(5, 9).with_offset(45967, 180),
+        }
+
+        // Standard: meter
+        Unit::Length(length) => match length {
+            LengthUnit::Meter(i) => Linear::power_of_ten(i as i32),
+
+            LengthUnit::Inch => Linear::ratio(254, 10_000),
+            LengthUnit::Feet => Linear::ratio(3048, 10_000),
+            LengthUnit::Yard => Linear::ratio(9144, 10_000),
+            LengthUnit::Mile => Linear::ratio(1_609_344, 1000),
+            LengthUnit::Mil => Linear::ratio(254, 10_000_000),
+            LengthUnit::NauticalMile => Linear::ratio(1852, 1),
+            LengthUnit::Fathom => Linear::ratio(3048 * 6, 10_000),
+            LengthUnit::Chain => Linear::ratio(3048 * 66, 10_000),
+            LengthUnit::Link => Linear::ratio(3048 * 66, 1_000_000),
+            LengthUnit::Rod => Linear::ratio(3048 * 33, 20_000),
+            LengthUnit::Furlong => Linear::ratio(3048 * 660, 10_000),
+            LengthUnit::League => Linear::ratio(1_609_344 * 3, 1000),
+            LengthUnit::SurveyFoot => Linear::ratio(1200, 3937),
+            LengthUnit::SurveyMile => Linear::ratio(1200 * 5280, 3937),
+            LengthUnit::Angstrom => Linear::power_of_ten(-10),
+            LengthUnit::AstronomicalUnit => Linear::ratio(149_597_870_700, 1),
+            // 365.25 days at the speed of light
+            LengthUnit::LightYear => Linear::ratio(9_460_730_472_580_800, 1),
+            LengthUnit::Parsec => Linear { pi: -1, ..Linear::ratio(648_000 * 149_597_870_700, 1) },
+            LengthUnit::Point => Linear::ratio(254, 720_000),
+            LengthUnit::Pica => Linear::ratio(254, 60_000),
+            LengthUnit::DidotPoint => Linear::ratio(376, 1_000_000),
+            LengthUnit::Cicero => Linear::ratio(376 * 12, 1_000_000),
+            LengthUnit::Twip => Linear::ratio(254, 14_400_000),
+            // A bad font size or resolution is refused by check_units
+            LengthUnit::Em => Linear {
+                factor: em_length(context).unwrap_or(Ratio::ZERO),
+                ..Linear::ratio(1, 1)
+            },
+            LengthUnit::Pixel => Linear {
+                factor: Ratio::new(254, 10_000).checked_div(context.dpi).unwrap_or(Ratio::ZERO),
+                ..Linear::ratio(1, 1)
+            },
+        }
+
+        // Standard: square meter
+        Unit::Area(area) => match area {
+            AreaUnit::Meter2(i) => Linear::power_of_ten(i as i32 * 2),
+
+            AreaUnit::Inch2 => Linear::ratio(254 * 254, 10_000 * 10_000),
+            AreaUnit::Feet2 => Linear::ratio(3048 * 3048, 10_000 * 10_000),
+            AreaUnit::Yard2 => Linear::ratio(9144 * 9144, 10_000 * 10_000),
+            AreaUnit::Mile2 => Linear::ratio(1_609_344 * 1_609_344, 1000 * 1000),
+            AreaUnit::Acre => Linear::ratio(40_468_564_224, 10_000_000),
+            AreaUnit::Hectare => Linear::ratio(10_000, 1),
+        }
+
+        // Standard: liter
+        Unit::Volume(vol) => match vol {
+            VolUnit::Liter(i) => Linear::power_of_ten(i as i32),
+            VolUnit::Meter3(i) => Linear::power_of_ten(i as i32 * 3 + 3),
+            VolUnit::TeaSpoon(_)
+            | VolUnit::TableSpoon(_)
+            | VolUnit::FluidOunce(_)
+            | VolUnit::Gill(_)
+            | VolUnit::Cup(_)
+            | VolUnit::Pint(_)
+            | VolUnit::Quart(_)
+            | VolUnit::Gallon(_)
+            | VolUnit::Peck(_)
+            | VolUnit::Bushel(_) => {
+                // Units missing from their system are refused by `check_units`
+                customary_volume(vol).unwrap_or(Linear::ratio(0, 1))
+            }
+            VolUnit::Inch3 => Linear::ratio(254 * 254 * 254, 1_000_000_000),
+            VolUnit::Feet3 => Linear::ratio(3048 * 3048 * 3048, 1_000_000_000),
+            VolUnit::Yard3 => Linear::ratio(9144 * 9144 * 9144, 1_000_000_000),
+            VolUnit::Barrel => Linear::ratio(42 * 3_785_411_784, 1_000_000_000),
+        }
+
+        // Standard: gram
+        Unit::Mass(mass) => match mass {
+            MassUnit::Gram(i) => Linear::power_of_ten(i as i32),
+            MassUnit::Ounce => Linear::ratio(45_359_237, 1_600_000),
+            MassUnit::Pound => Linear::ratio(45_359_237, 100_000),
+            MassUnit::Stone => Linear::ratio(45_359_237 * 14, 100_000),
+            MassUnit::ShortHundredweight => Linear::ratio(45_359_237 * 100, 100_000),
+            MassUnit::LongHundredweight => Linear::ratio(45_359_237 * 112, 100_000),
+            MassUnit::ShortTon => Linear::ratio(45_359_237 * 2000, 100_000),
+            MassUnit::LongTon => Linear::ratio(45_359_237 * 2240, 100_000),
+            MassUnit::Tonne => Linear::power_of_ten(6),
+            MassUnit::Dram => Linear::ratio(45_359_237, 25_600_000),
+            MassUnit::Grain => Linear::ratio(6_479_891, 100_000_000),
+            MassUnit::TroyOunce => Linear::ratio(6_479_891 * 480, 100_000_000),
+            MassUnit::TroyPound => Linear::ratio(6_479_891 * 5760, 100_000_000),
+            MassUnit::Pennyweight => Linear::ratio(6_479_891 * 24, 100_000_000),
+            MassUnit::ApothecaryDram => Linear::ratio(6_479_891 * 60, 100_000_000),
+            MassUnit::Scruple => Linear::ratio(6_479_891 * 20, 100_000_000),
+            MassUnit::Carat => Linear::ratio(200, 1000),
+            // One pound times standard gravity over one foot
+            MassUnit::Slug => Linear::ratio(45_359_237 * 980_665, 3048 * 1_000_000),
+        }
+
+        // Standard: meter per second
+        Unit::Speed(speed) => match speed {
+            SpeedUnit::MeterPerSecond(i) => Linear::power_of_ten(i as i32),
+            SpeedUnit::KilometerPerHour => Linear::ratio(1000, 3600),
+            SpeedUnit::MilePerHour => Linear::ratio(1_609_344, 3_600_000),
+            SpeedUnit::FootPerSecond => Linear::ratio(3048, 10_000),
+            SpeedUnit::Knot => Linear::ratio(1852, 3600),
+            SpeedUnit::Mach => Linear::ratio(340_294, 1000),
+            SpeedUnit::SpeedOfLight => Linear::ratio(299_792_458, 1),
+        }
+
+        // Standard: second
+        Unit::Time(time) => match time {
+            TimeUnit::Second(i) => Linear::power_of_ten(i as i32),
+            TimeUnit::Minute => Linear::ratio(60, 1),
+            TimeUnit::Hour => Linear::ratio(3600, 1),
+            TimeUnit::Day => Linear::ratio(86_400, 1),
+            TimeUnit::Week => Linear::ratio(604_800, 1),
+            TimeUnit::JulianYear => Linear::ratio(31_557_600, 1),
+        }
+
+        // Standard: pascal (absolute)
+        Unit::Pressure(pressure) => match pressure {
+            PressureUnit::Pascal(i) => Linear::power_of_ten(i as i32),
+            PressureUnit::Bar(i) => Linear::power_of_ten(i as i32 + 5),
+            PressureUnit::Atmosphere => Linear::ratio(101_325, 1),
+            // lbf/in² = 0.45359237 kg × 9.80665 m/s² / (0.0254 m)²
+            PressureUnit::Psi => Linear::ratio(45_359_237 * 980_665, 64_516 * 100_000),
+            PressureUnit::Psig => Linear {
+                offset: context.reference_pressure,
+                ..Linear::ratio(45_359_237 * 980_665, 64_516 * 100_000)
+            },
+            // Conventional mercury column: 13,595.1 kg/m³ under standard gravity
+            PressureUnit::MillimeterOfMercury => Linear::ratio(135_951 * 980_665, 1_000_000_000),
+            PressureUnit::InchOfMercury => Linear::ratio(135_951 * 254 * 980_665, 10_000_000_000),
+            PressureUnit::Torr => Linear::ratio(101_325, 760),
+            PressureUnit::KilogramForcePerCm2 => Linear::ratio(980_665, 10),
+        }
+
+        // Standard: joule
+        Unit::Energy(energy) => match energy {
+            EnergyUnit::Joule(i) => Linear::power_of_ten(i as i32),
+            EnergyUnit::WattHour(i) => Linear { exp: i as i32, ..Linear::ratio(3600, 1) },
+            EnergyUnit::Calorie(i) => Linear { exp: i as i32, ..Linear::ratio(4184, 1000) },
+            EnergyUnit::Electronvolt(i) => Linear {
+                exp: i as i32 - 28,
+                ..Linear::ratio(1_602_176_634, 1)
+            },
+            EnergyUnit::CalorieIt => Linear::ratio(41_868, 10_000),
+            EnergyUnit::KilocalorieIt => Linear::ratio(41_868, 10),
+            EnergyUnit::FoodCalorie => Linear::ratio(4184, 1),
+            // The heat that warms one pound of water by one degree Fahrenheit
+            EnergyUnit::BtuIt => Linear::ratio(41_868 * 45_359_237 * 5, 10_000 * 100_000 * 9),
+            EnergyUnit::BtuThermochemical => Linear::ratio(4184 * 45_359_237 * 5, 1000 * 100_000 * 9),
+            EnergyUnit::Erg => Linear::power_of_ten(-7),
+            EnergyUnit::Therm => Linear::ratio(41_868 * 45_359_237 * 5, 10_000 * 9),
+            // 0.3048 m × 0.45359237 kg × 9.80665 m/s²
+            EnergyUnit::FootPound => Linear::ratio(3048 * 45_359_237 * 980_665, 10_000 * 100_000_000 * 100_000),
+        }
+
+        // Standard: watt
+        Unit::Power(power) => match power {
+            PowerUnit::Watt(i) => Linear::power_of_ten(i as i32),
+            // 550 × 0.3048 m × 0.45359237 kg × 9.80665 m/s² per second
+            PowerUnit::HorsepowerMechanical => {
+                Linear::ratio(550 * 3048 * 45_359_237 * 980_665, 10_000 * 100_000_000 * 100_000)
+            }
+            PowerUnit::HorsepowerMetric => Linear::ratio(75 * 980_665, 100_000),
+            PowerUnit::HorsepowerElectrical => Linear::ratio(746, 1),
+            // NIST SP 811 value
+            PowerUnit::HorsepowerBoiler => Linear::ratio(98_095, 10),
+            PowerUnit::BtuPerHour => Linear::ratio(41_868 * 45_359_237 * 5, 10_000 * 100_000 * 9 * 3600),
+            PowerUnit::TonOfRefrigeration => {
+                Linear::ratio(12_000 * 41_868 * 45_359_237 * 5, 10_000 * 100_000 * 9 * 3600)
+            }
+            PowerUnit::ErgPerSecond => Linear::power_of_ten(-7),
+            PowerUnit::DecibelMilliwatt => return Conversion::Decibel(Ratio::new(1, 1000)),
+            PowerUnit::DecibelWatt => return Conversion::Decibel(Ratio::ONE),
+        }
+
+        // Standard: bit
+        Unit::Data(data) => match data {
+            DataUnit::Bit(i) => Linear::power_of_ten(i as i32),
+            DataUnit::Byte(i) => Linear { exp: i as i32, ..Linear::ratio(8, 1) },
+            DataUnit::BinaryBit(i) => Linear::binary(1, i),
+            DataUnit::BinaryByte(i) => Linear::binary(8, i),
+            DataUnit::Nibble => Linear::ratio(4, 1),
+        }
+
+        // Standard: bit per second
+        Unit::DataRate(rate) => match rate {
+            DataRateUnit::BitPerSecond(i) => Linear::power_of_ten(i as i32),
+            DataRateUnit::BytePerSecond(i) => Linear { exp: i as i32, ..Linear::ratio(8, 1) },
+            DataRateUnit::BinaryBitPerSecond(i) => Linear::binary(1, i),
+            DataRateUnit::BinaryBytePerSecond(i) => Linear::binary(8, i),
+        }
+
+        // Standard: radian
+        Unit::Angle(angle) => match angle {
+            AngleUnit::Radian(i) => Linear::power_of_ten(i as i32),
+            AngleUnit::Degree => Linear::pi_ratio(1, 180),
+            AngleUnit::Arcminute => Linear::pi_ratio(1, 180 * 60),
+            AngleUnit::Arcsecond => Linear::pi_ratio(1, 180 * 3600),
+            AngleUnit::Gradian => Linear::pi_ratio(1, 200),
+            AngleUnit::Turn => Linear::pi_ratio(2, 1),
+            AngleUnit::MilNato => Linear::pi_ratio(2, 6400),
+            AngleUnit::MilWarsawPact => Linear::pi_ratio(2, 6000),
+            AngleUnit::MilSwedish => Linear::pi_ratio(2, 6300),
+        }
+
+        // Standard: steradian
+        Unit::SolidAngle(solid) => match solid {
+            SolidAngleUnit::Steradian(i) => Linear::power_of_ten(i as i32),
+            SolidAngleUnit::SquareDegree => Linear { pi: 2, ..Linear::ratio(1, 180 * 180) },
+        }
+
+        // Standard: newton
+        Unit::Force(force) => match force {
+            ForceUnit::Newton(i) => Linear::power_of_ten(i as i32),
+            ForceUnit::Dyne => Linear::power_of_ten(-5),
+            ForceUnit::KilogramForce => Linear::ratio(980_665, 100_000),
+            // 0.45359237 kg × 9.80665 m/s²
+            ForceUnit::PoundForce => Linear::ratio(45_359_237 * 980_665, 100_000_000 * 100_000),
+            // 0.45359237 kg × 0.3048 m/s²
+            ForceUnit::Poundal => Linear::ratio(45_359_237 * 3048, 100_000_000 * 10_000),
+            ForceUnit::Kip => Linear::ratio(1000 * 45_359_237 * 980_665, 100_000_000 * 100_000),
+        }
+
+        // Standard: newton-meter
+        Unit::Torque(torque) => match torque {
+            TorqueUnit::NewtonMeter(i) => Linear::power_of_ten(i as i32),
+            TorqueUnit::PoundForceFoot => Linear::ratio(45_359_237 * 980_665 * 3048, 100_000_000 * 100_000 * 10_000),
+            TorqueUnit::PoundForceInch => Linear::ratio(45_359_237 * 980_665 * 254, 100_000_000 * 100_000 * 10_000),
+            TorqueUnit::KilogramForceMeter => Linear::ratio(980_665, 100_000),
+        }
+
+        // Standard: hertz
+        Unit::Frequency(frequency) => match frequency {
+            FrequencyUnit::Hertz(i) => Linear::power_of_ten(i as i32),
+            FrequencyUnit::RevolutionPerMinute => Linear::ratio(1, 60),
+            FrequencyUnit::RadianPerSecond => Linear { pi: -1, ..Linear::ratio(1, 2) },
+            FrequencyUnit::CyclePerMinute => Linear::ratio(1, 60),
+            FrequencyUnit::BeatPerMinute => Linear::ratio(1, 60),
+        }
+
+        // Standard: kilometers per liter
+        Unit::FuelEconomy(economy) => match economy {
+            FuelEconomyUnit::LitersPer100Km => return Conversion::Reciprocal(Ratio::from(100)),
+            FuelEconomyUnit::KilometersPerLiter => Linear::ratio(1, 1),
+            // 1.609344 km per 3.785411784 L
+            FuelEconomyUnit::MilesPerGallonUs => Linear::ratio(1_609_344 * 1000, 3_785_411_784),
+            // 1.609344 km per 4.54609 L
+            FuelEconomyUnit::MilesPerGallonUk => Linear::ratio(1_609_344, 4_546_090),
+        }
+
+        // Standard: gram per liter, which is also kg/m³
+        Unit::Density(density) => match density {
+            DensityUnit::MassPerVolume(mass, vol) => per_conversion(Unit::Mass(mass), Unit::Volume(vol), context),
+            DensityUnit::SpecificGravity => Linear::ratio(999_972, 1000),
+        }
+
+        // Standard: gram per liter, as for density
+        Unit::Concentration(concentration) => match concentration {
+            // Without a molar mass only molarities can be compared, and for
+            // those any molar mass cancels out
+            ConcentrationUnit::MolePerLiter(i) => Linear {
+                factor: context.molar_mass.unwrap_or(Ratio::ONE),
+                ..Linear::power_of_ten(i as i32)
+            },
+            ConcentrationUnit::PartsPerMillion => Linear::ratio(1, 1000),
+            ConcentrationUnit::PartsPerBillion => Linear::ratio(1, 1_000_000),
+            ConcentrationUnit::PercentWeightPerVolume => Linear::ratio(10, 1),
+        }
+
+        // Standard: liter per second
+        Unit::FlowRate(FlowRateUnit::VolumePerTime(vol, time)) => {
+            per_conversion(Unit::Volume(vol), Unit::Time(time), context)
+        }
+
+        // Standard: gram per second
+        Unit::MassFlow(MassFlowUnit::MassPerTime(mass, time)) => {
+            per_conversion(Unit::Mass(mass), Unit::Time(time), context)
+        }
+
+        // Standard: coulomb
+        Unit::Charge(charge) => match charge {
+            ChargeUnit::Coulomb(i) => Linear::power_of_ten(i as i32),
+            ChargeUnit::AmpereHour(i) => Linear { exp: i as i32, ..Linear::ratio(3600, 1) },
+        }
+
+        // Standard: ampere
+        Unit::Current(CurrentUnit::Ampere(i)) => Linear::power_of_ten(i as i32),
+
+        // Standard: volt
+        Unit::Voltage(VoltageUnit::Volt(i)) => Linear::power_of_ten(i as i32),
+
+        // Standard: ohm
+        Unit::Resistance(ResistanceUnit::Ohm(i)) => Linear::power_of_ten(i as i32),
+
+        // Standard: siemens
+        Unit::Conductance(ConductanceUnit::Siemens(i)) => Linear::power_of_ten(i as i32),
+
+        // Standard: farad
+        Unit::Capacitance(CapacitanceUnit::Farad(i)) => Linear::power_of_ten(i as i32),
+
+        // Standard: henry
+        Unit::Inductance(InductanceUnit::Henry(i)) => Linear::power_of_ten(i as i32),
+
+        // Standard: tesla
+        Unit::MagneticFluxDensity(flux_density) => match flux_density {
+            MagneticFluxDensityUnit::Tesla(i) => Linear::power_of_ten(i as i32),
+            MagneticFluxDensityUnit::Gauss => Linear::power_of_ten(-4),
+        }
+
+        // Standard: weber
+        Unit::MagneticFlux(MagneticFluxUnit::Weber(i)) => Linear::power_of_ten(i as i32),
+
+        // Standard: candela
+        Unit::LuminousIntensity(LuminousIntensityUnit::Candela(i)) => Linear::power_of_ten(i as i32),
+
+        // Standard: lumen
+        Unit::LuminousFlux(LuminousFluxUnit::Lumen(i)) => Linear::power_of_ten(i as i32),
+
+        // Standard: lux
+        Unit::Illuminance(unit) => match unit {
+            IlluminanceUnit::Lux(i) => Linear::power_of_ten(i as i32),
+            IlluminanceUnit::FootCandle => Linear::ratio(100_000_000, 9_290_304),
+            IlluminanceUnit::Phot => Linear::power_of_ten(4),
+        }
+
+        // Standard: nit
+        Unit::Luminance(luminance) => match luminance {
+            LuminanceUnit::Nit => Linear::ratio(1, 1),
+            LuminanceUnit::FootLambert => Linear { pi: -1, ..Linear::ratio(100_000_000, 9_290_304) },
+        }
+
+        // Standard: becquerel
+        Unit::Radioactivity(activity) => match activity {
+            RadioactivityUnit::Becquerel(i) => Linear::power_of_ten(i as i32),
+            RadioactivityUnit::Curie(i) => Linear { exp: i as i32 + 10, ..Linear::ratio(37, 10) },
+            RadioactivityUnit::Rutherford => Linear::power_of_ten(6),
+            RadioactivityUnit::DisintegrationPerMinute => Linear::ratio(1, 60),
+        }
+
+        // Standard: gray
+        Unit::AbsorbedDose(dose) => match dose {
+            AbsorbedDoseUnit::Gray(i) => Linear::power_of_ten(i as i32),
+            AbsorbedDoseUnit::Rad(i) => Linear::power_of_ten(i as i32 - 2),
+        }
+
+        // Standard: sievert
+        Unit::EquivalentDose(dose) => match dose {
+            EquivalentDoseUnit::Sievert(i) => Linear::power_of_ten(i as i32),
+            EquivalentDoseUnit::Rem(i) => Linear::power_of_ten(i as i32 - 2),
+        }
+
+        // Standard: meter per second squared
+        Unit::Acceleration(acceleration) => match acceleration {
+            AccelerationUnit::MeterPerSecond2(i) => Linear::power_of_ten(i as i32),
+            AccelerationUnit::FootPerSecond2 => Linear::ratio(3048, 10_000),
+            AccelerationUnit::StandardGravity => Linear::ratio(980_665, 100_000),
+            AccelerationUnit::Gal(i) => Linear::power_of_ten(i as i32 - 2),
+        }
+
+        // Standard: gram, through the molar mass. As with molarities, any
+        // molar mass cancels out between two amounts
+        Unit::Amount(amount) => {
+            let grams_per_mole = context.molar_mass.unwrap_or(Ratio::ONE);
+            match amount {
+                AmountUnit::Mole(i) => Linear { factor: grams_per_mole, ..Linear::power_of_ten(i as i32) },
+                // An overflow is refused by check_units
+                AmountUnit::Particle => Linear {
+                    factor: particle_mass(context).unwrap_or(Ratio::ZERO),
+                    exp: -23,
+                    ..Linear::ratio(1, 1)
+                },
+            }
+        }
+    };
+    Conversion::Linear(linear)
+}
+
+fn convert_to_standard<T: Real>(value: T, unit: Unit, context: &ConversionContext) -> T {
+    match conversion(unit, context) {
+        Conversion::Linear(linear) => {
+            value * T::from_f64(linear.factor_f64()) + T::from_f64(linear.offset.to_f64())
+        }
+        Conversion::Decibel(reference) => {
+            T::from_f64(reference.to_f64() * 10f64.powf(value.to_f64() / 10.0))
+        }
+        Conversion::Reciprocal(k) => T::from_f64(k.to_f64()) / value,
+    }
+}
+
+fn convert_from_standard<T: Real>(value: T, unit: Unit, context: &ConversionContext) -> T {
+    match conversion(unit, context) {
+        Conversion::Linear(linear) => {
+            (value - T::from_f64(linear.offset.to_f64())) / T::from_f64(linear.factor_f64())
+        }
+        Conversion::Decibel(reference) => {
+            T::from_f64(10.0 * (value.to_f64() / reference.to_f64()).log10())
+        }
+        Conversion::Reciprocal(k) => T::from_f64(k.to_f64()) / value,
+    }
+}
+
+/// `k / value`, where zero has no finite result.
+fn reciprocal_exact(k: Ratio, value: Ratio) -> Result<Ratio, ConversionError> {
+    if value.is_zero() {
+        return Err(ConversionError::NonFiniteResult);
+    }
+    k.checked_div(value).ok_or(ConversionError::Overflow)
+}
+
+/// Whether values in `a` and `b` measure the same thing. A mass
+/// concentration is the density of one part of a solution, so those two
+/// dimensions are the same as well.
+fn compatible(a: Dimension, b: Dimension) -> bool {
+    match (a, b) {
+        (Dimension::Density, Dimension::Concentration) | (Dimension::Concentration, Dimension::Density) => true,
+        (Dimension::Mass, Dimension::Amount) | (Dimension::Amount, Dimension::Mass) => true,
+        _ => a == b,
+    }
+}
+
+/// The mass of one particle in units of 10⁻²³ g: the molar mass over the
+/// Avogadro constant. `None` if a huge molar mass overflows it.
+fn particle_mass(context: &ConversionContext) -> Option<Ratio> {
+    Ratio::new(100_000_000, 602_214_076).checked_mul(context.molar_mass.unwrap_or(Ratio::ONE))
+}
+
+/// The length of an em in meters: the font size in points. `None` if a
+/// huge font size overflows it.
+fn em_length(context: &ConversionContext) -> Option<Ratio> {
+    context.font_size.checked_mul(Ratio::new(254, 720_000))
+}
+
+/// Why `unit` cannot be converted with the settings in `context`, if it
+/// cannot.
+fn context_error(unit: Unit, context: &ConversionContext) -> Option<ConversionError> {
+    match unit {
+        Unit::Amount(AmountUnit::Particle) if particle_mass(context).is_none() => Some(ConversionError::Overflow),
+        Unit::Length(LengthUnit::Pixel) if context.dpi <= Ratio::ZERO => Some(ConversionError::InvalidDpi),
+        Unit::Length(LengthUnit::Em) if context.font_size <= Ratio::ZERO => Some(ConversionError::InvalidFontSize),
+        Unit::Length(LengthUnit::Em) if em_length(context).is_none() => Some(ConversionError::Overflow),
+        _ => None,
+    }
+}
+
+/// Whether converting between `a` and `b` goes between moles and grams
+/// without a molar mass to do it with.
+fn needs_molar_mass(a: Unit, b: Unit, context: &ConversionContext) -> bool {
+    a.is_molar() != b.is_molar() && context.molar_mass.is_none()
+}
+
+fn check_units(a: Unit, b: Unit, context: &ConversionContext) -> Result<(), ConversionError> {
+    if !compatible(a.dimension(), b.dimension()) {
+        return Err(ConversionError::IncompatibleDimensions(a.dimension(), b.dimension()));
+    }
+    if needs_molar_mass(a, b, context) {
+        return Err(ConversionError::MolarMassRequired);
+    }
+
+    for unit in a.parts().into_iter().chain(b.parts()) {
+        if !unit.exists() {
+            return Err(ConversionError::UnsupportedSystem(unit));
+        }
+        if let Some(err) = context_error(unit, context) {
+            return Err(err);
+        }
+        if let Some(i) = unit.scale() {
+            if scale_to_string(i).is_none() {
+                return Err(ConversionError::UnsupportedScale(i));
+            }
+        }
+        if let Some(i) = unit.binary_scale() {
+            if binary_scale_to_string(i).is_none() {
+                return Err(ConversionError::UnsupportedBinaryScale(i));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// In meters per second.
+const SPEED_OF_LIGHT: f64 = 299_792_458.0;
+
+/// Settings that some conversions depend on, such as the atmosphere that
+/// gauge pressures are measured against.
+///
+/// The free functions [`convert`], [`try_convert`] and friends all use
+/// [`ConversionContext::default`].
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct ConversionContext {
+    /// Ambient pressure that gauge units are relative to, in pascals.
+    /// Defaults to one standard atmosphere, 101,325 Pa.
+    pub reference_pressure: Ratio,
+    /// Molar mass of the solute in grams per mole, which molarities and
+    /// amounts need to convert to and from mass concentrations and masses.
+    /// Unset by default; see [`molar_mass`] to work it out from a formula.
+    pub molar_mass: Option<Ratio>,
+    /// Screen or printer resolution in dots per inch, which pixels convert
+    /// with. Defaults to 96, the CSS reference pixel.
+    pub dpi: Ratio,
+    /// Font size in points, the length of an em. Defaults to 12 points,
+    /// 16 pixels at 96 dpi.
+    pub font_size: Ratio,
+}
+
+impl Default for ConversionContext {
+    fn default() -> ConversionContext {
+        ConversionContext {
+            reference_pressure: Ratio::from(101_325),
+            molar_mass: None,
+            dpi: Ratio::from(96),
+            font_size: Ratio::from(12),
+        }
+    }
+}
+
+impl ConversionContext {
+    /// See [`convert`].
+    pub fn convert(&self, value: f64, a: Unit, b: Unit) -> f64 {
+        self.convert_real(value, a, b)
+    }
+
+    /// See [`convert_real`].
+    pub fn convert_real<T: Real>(&self, value: T, a: Unit, b: Unit) -> T {
+        // Gray and sievert are both J/kg, so a mismatch would otherwise
+        // convert silently, as would moles to grams at 1 g/mol
+        let unusable = |unit: Unit| !unit.exists() || context_error(unit, self).is_some();
+        if !compatible(a.dimension(), b.dimension()) || unusable(a) || unusable(b) || needs_molar_mass(a, b, self) {
+            return T::from_f64(f64::NAN);
+        }
+
+        // Folding both maps into one exact factor rounds only once; going
+        // through the standard unit is the fallback for extreme scales.
+        if let (Conversion::Linear(from), Conversion::Linear(to)) = (conversion(a, self), conversion(b, self)) {
+            if let Some(linear) = from.then_inverse(to) {
+                return value * T::from_f64(linear.factor_f64()) + T::from_f64(linear.offset.to_f64());
+            }
+        }
+        convert_from_standard(
+            convert_to_standard(value, a, self),
+            b,
+            self
+        )
+    }
+
+    /// See [`try_convert`].
+    pub fn try_convert(&self, value: f64, a: Unit, b: Unit) -> Result<f64, ConversionError> {
+        self.try_convert_real(value, a, b)
+    }
+
+    /// See [`try_convert_real`].
+    pub fn try_convert_real<T: Real>(&self, value: T, a: Unit, b: Unit) -> Result<T, ConversionError> {
+        check_units(a, b, self)?;
+
+        if !value.is_finite() {
+            return Err(ConversionError::NonFiniteValue);
+        }
+
+        let converted = self.convert_real(value, a, b);
+        if !converted.is_finite() {
+            return Err(ConversionError::NonFiniteResult);
+        }
+
+        Ok(converted)
+    }
+
+    /// See [`try_convert_wavelength`].
+    pub fn try_convert_wavelength(&self, value: f64, a: Unit, b: Unit) -> Result<f64, ConversionError> {
+        let meter = Unit::Length(LengthUnit::Meter(0));
+        let hertz = Unit::Frequency(FrequencyUnit::Hertz(0));
+        let (from, to) = match (a.dimension(), b.dimension()) {
+            (Dimension::Length, Dimension::Frequency) => (meter, hertz),
+            (Dimension::Frequency, Dimension::Length) => (hertz, meter),
+            _ => return self.try_convert(value, a, b),
+        };
+        check_units(a, from, self)?;
+        check_units(to, b, self)?;
+        if !value.is_finite() {
+            return Err(ConversionError::NonFiniteValue);
+        }
+
+        // f = c / λ and λ = c / f. Folding both units into one exact factor
+        // first rounds only once, so 500 nm is exactly 599.584916 THz.
+        let (Conversion::Linear(from), Conversion::Linear(to)) = (conversion(a, self), conversion(b, self)) else {
+            unreachable!("lengths and frequencies are linear");
+        };
+        let factor = match from.times(to) {
+            Some(linear) => linear.factor_f64(),
+            None => from.factor_f64() * to.factor_f64(),
+        };
+        let converted = SPEED_OF_LIGHT / (value * factor);
+        if !converted.is_finite() {
+            return Err(ConversionError::NonFiniteResult);
+        }
+
+        Ok(converted)
+    }
+
+    pub fn convert_exact(&self, value: Ratio, a: Unit, b: Unit) -> Result<Ratio, ConversionError> {
+        check_units(a, b, self)?;
+
+        // A reciprocal unit is turned into the standard unit on the way in
+        // and back out of it on the way out, which keeps both exact
+        let (value, from) = match conversion(a, self) {
+            Conversion::Linear(from) => (value, from),
+            Conversion::Reciprocal(k) => (reciprocal_exact(k, value)?, Linear::ratio(1, 1)),
+            Conversion::Decibel(_) => return Err(ConversionError::Inexact),
+        };
+        let (to, reciprocal) = match conversion(b, self) {
+            Conversion::Linear(to) => (to, None),
+            Conversion::Reciprocal(k) => (Linear::ratio(1, 1), Some(k)),
+            Conversion::Decibel(_) => return Err(ConversionError::Inexact),
+        };
+
+        let linear = from.then_inverse(to).ok_or(ConversionError::Overflow)?;
+        if linear.pi != 0 {
+            return Err(ConversionError::Inexact);
+        }
+        let converted = value
+            .checked_mul(linear.factor)
+            .and_then(|v| v.checked_scale10(linear.exp))
+            .and_then(|v| v.checked_add(linear.offset))
+            .ok_or(ConversionError::Overflow)?;
+        match reciprocal {
+            Some(k) => reciprocal_exact(k, converted),
+            None => Ok(converted),
+        }
+    }
+}
+
+/// Converts `value` from unit `a` to unit `b` with no validation beyond
+/// returning NaN when the units measure different dimensions or need a
+/// [`ConversionContext::molar_mass`].
+///
+/// Prefer [`try_convert`], which says why a conversion was refused.
+pub fn convert(value: f64, a: Unit, b: Unit) -> f64 {
+    ConversionContext::default().convert(value, a, b)
+}
+
+/// [`convert`] for any [`Real`] type, e.g. `f32`.
+pub fn convert_real<T: Real>(value: T, a: Unit, b: Unit) -> T {
+    ConversionContext::default().convert_real(value, a, b)
+}
+
+/// Converts `value` from unit `a` to unit `b`, checking that both units
+/// measure the same dimension and that input and output are finite.
+pub fn try_convert(value: f64, a: Unit, b: Unit) -> Result<f64, ConversionError> {
+    ConversionContext::default().try_convert(value, a, b)
+}
+
+/// [`try_convert`] for any [`Real`] type, e.g. `f32`.
+pub fn try_convert_real<T: Real>(value: T, a: Unit, b: Unit) -> Result<T, ConversionError> {
+    ConversionContext::default().try_convert_real(value, a, b)
+}
+
+/// Like [`try_convert`], but also converts between the wavelength and the
+/// frequency of electromagnetic radiation in vacuum, e.g. 500 nm to THz.
+///
+/// Unlike every other conversion this is not linear: doubling a wavelength
+/// halves the frequency, and zero maps to infinity, which is reported as
+/// [`ConversionError::NonFiniteResult`].
+pub fn try_convert_wavelength(value: f64, a: Unit, b: Unit) -> Result<f64, ConversionError> {
+    ConversionContext::default().try_convert_wavelength(value, a, b)
+}
+
+/// Converts an exact fraction from unit `a` to unit `b` without any rounding,
+/// so that e.g. inches to centimeters and back returns the original value.
+pub fn convert_exact(value: Ratio, a: Unit, b: Unit) -> Result<Ratio, ConversionError> {
+    ConversionContext::default().convert_exact(value, a, b)
+}
+
+impl ConversionContext {
+    /// See [`convert_and_print_to`].
+    pub fn convert_and_print_to(&self, value: f64, a: Unit, b: Unit) -> Result<(), ConversionError> {
+        let converted = self.try_convert(value, a, b)?;
+
+        println!("{value} {a} equals to...");
+        println!("\t {converted} {b}");
+
+        Ok(())
+    }
+
+    /// See [`convert_and_print_all`].
+    pub fn convert_and_print_all(&self, value: f64, a: Unit) -> Result<(), ConversionError> {
+        let units = fetch_all_units(a);
+        let mut results = Vec::with_capacity(units.len());
+        for unit in units {
+            if unit == a {
+                continue;
+            } else {
+                results.push((self.try_convert(value, a, unit)?, unit));
+            }
+        }
+
+        println!("{value} {a} equals to...");
+
+        let mut system = None;
+        for (converted, unit) in results {
+            if let Some(heading) = mass_system(unit).filter(|&heading| Some(heading) != system) {
+                println!("  {heading}");
+                system = Some(heading);
+            }
+            println!("\t {converted} {unit}");
+        }
+
+        Ok(())
+    }
+
+    /// See [`convert_and_print_wavelength`].
+    pub fn convert_and_print_wavelength(&self, value: f64, a: Unit, b: Unit) -> Result<(), ConversionError> {
+        let converted = self.try_convert_wavelength(value, a, b)?;
+
+        println!("{value} {a} equals to...");
+        println!("\t {converted} {b}");
+
+        Ok(())
+    }
+
+    /// See [`convert_and_print_substance`]; `molar_mass` replaces
+    /// [`ConversionContext::molar_mass`].
+    pub fn convert_and_print_substance(
+        &self,
+        value: f64,
+        a: Unit,
+        b: Unit,
+        formula: &str,
+        molar_mass: Ratio,
+    ) -> Result<(), ConversionError> {
+        let context = ConversionContext { molar_mass: Some(molar_mass), ..*self };
+        let converted = context.try_convert(value, a, b)?;
+
+        println!("{value} {a} of {formula} ({} g/mol) equals to...", molar_mass.to_f64());
+        println!("\t {converted} {b}");
+
+        Ok(())
+    }
+
+    /// See [`convert_and_print_duration`].
+    pub fn convert_and_print_duration(&self, value: f64, a: Unit) -> Result<(), ConversionError> {
+        let seconds = self.try_convert(value, a, Unit::Time(TimeUnit::Second(0)))?;
+
+        println!("{value} {a} equals to...");
+        println!("\t {}", format_duration(seconds));
+
+        Ok(())
+    }
+}
+
+pub fn convert_and_print_to(value: f64, a: Unit, b: Unit) -> Result<(), ConversionError> {
+    ConversionContext::default().convert_and_print_to(value, a, b)
+}
+
+/// [`convert_and_print_to`] using [`try_convert_wavelength`].
+pub fn convert_and_print_wavelength(value: f64, a: Unit, b: Unit) -> Result<(), ConversionError> {
+    ConversionContext::default().convert_and_print_wavelength(value, a, b)
+}
+
+/// [`convert_and_print_to`] for the substance `formula`, whose
+/// [`molar_mass`] is given, e.g. grams of glucose to millimoles.
+pub fn convert_and_print_substance(
+    value: f64,
+    a: Unit,
+    b: Unit,
+    formula: &str,
+    molar_mass: Ratio,
+) -> Result<(), ConversionError> {
+    ConversionContext::default().convert_and_print_substance(value, a, b, formula, molar_mass)
+}
+
+/// Prints a time as a normalised compound duration such as "1h 30m".
+pub fn convert_and_print_duration(value: f64, a: Unit) -> Result<(), ConversionError> {
+    ConversionContext::default().convert_and_print_duration(value, a)
+}
+
+fn fetch_all_units(unit: Unit) -> Vec<Unit> {
+    match unit {
+        Unit::Temperature(_) => vec![
+            Unit::Temperature(TempUnit::Kelvin),
+            Unit::Temperature(TempUnit::Celsius),
+            Unit::Temperature(TempUnit::Fahrenheit)
+        ],
+        Unit::Length(_) => vec![
+            Unit::Length(LengthUnit::Meter(-3)),
+            Unit::Length(LengthUnit::Meter(-2)),
+            Unit::Length(LengthUnit::Meter(0)),
+            Unit::Length(LengthUnit::Meter(3)),
 
             Unit::Length(LengthUnit::Inch),
             Unit::Length(LengthUnit::Feet),
             Unit::Length(LengthUnit::Yard),
             Unit::Length(LengthUnit::Mile),
+            Unit::Length(LengthUnit::Mil),
+            Unit::Length(LengthUnit::NauticalMile),
+            Unit::Length(LengthUnit::Fathom),
+
+            Unit::Length(LengthUnit::Link),
+            Unit::Length(LengthUnit::Rod),
+            Unit::Length(LengthUnit::Chain),
+            Unit::Length(LengthUnit::Furlong),
+            Unit::Length(LengthUnit::League),
+            Unit::Length(LengthUnit::SurveyFoot),
+            Unit::Length(LengthUnit::SurveyMile),
+
+            Unit::Length(LengthUnit::Angstrom),
+            Unit::Length(LengthUnit::AstronomicalUnit),
+            Unit::Length(LengthUnit::LightYear),
+            Unit::Length(LengthUnit::Parsec),
+
+            Unit::Length(LengthUnit::Point),
+            Unit::Length(LengthUnit::Pica),
+            Unit::Length(LengthUnit::DidotPoint),
+            Unit::Length(LengthUnit::Cicero),
+            Unit::Length(LengthUnit::Twip),
+            Unit::Length(LengthUnit::Em),
+            Unit::Length(LengthUnit::Pixel),
         ],
         Unit::Area(_) => vec![
             Unit::Area(AreaUnit::Meter2(-3)),
@@ -323,37 +2960,463 @@ fn fetch_all_units(unit: Unit) -> Vec<Unit> {
             Unit::Volume(VolUnit::Meter3(0)),
             Unit::Volume(VolUnit::Meter3(3)),
 
-            Unit::Volume(VolUnit::TeaSpoon),
-            Unit::Volume(VolUnit::TableSpoon),
-            Unit::Volume(VolUnit::Cup),
-            Unit::Volume(VolUnit::Pint),
-            Unit::Volume(VolUnit::Gallon),
+            Unit::Volume(VolUnit::TeaSpoon(VolumeSystem::Metric)),
+            Unit::Volume(VolUnit::TableSpoon(VolumeSystem::Metric)),
+            Unit::Volume(VolUnit::Cup(VolumeSystem::Metric)),
+
+            Unit::Volume(VolUnit::TeaSpoon(VolumeSystem::UsLiquid)),
+            Unit::Volume(VolUnit::TableSpoon(VolumeSystem::UsLiquid)),
+            Unit::Volume(VolUnit::FluidOunce(VolumeSystem::UsLiquid)),
+            Unit::Volume(VolUnit::Cup(VolumeSystem::UsLiquid)),
+            Unit::Volume(VolUnit::Pint(VolumeSystem::UsLiquid)),
+            Unit::Volume(VolUnit::Quart(VolumeSystem::UsLiquid)),
+            Unit::Volume(VolUnit::Gallon(VolumeSystem::UsLiquid)),
+
+            Unit::Volume(VolUnit::FluidOunce(VolumeSystem::Imperial)),
+            Unit::Volume(VolUnit::Pint(VolumeSystem::Imperial)),
+            Unit::Volume(VolUnit::Quart(VolumeSystem::Imperial)),
+            Unit::Volume(VolUnit::Gallon(VolumeSystem::Imperial)),
+
+            Unit::Volume(VolUnit::Peck(VolumeSystem::UsDry)),
+            Unit::Volume(VolUnit::Bushel(VolumeSystem::UsDry)),
+
+            Unit::Volume(VolUnit::Inch3),
+            Unit::Volume(VolUnit::Feet3),
+            Unit::Volume(VolUnit::Yard3),
+            Unit::Volume(VolUnit::Barrel),
         ],
+        // In the order of `mass_system`'s groups
         Unit::Mass(_) => vec![
             Unit::Mass(MassUnit::Gram(-3)),
             Unit::Mass(MassUnit::Gram(0)),
             Unit::Mass(MassUnit::Gram(3)),
+            Unit::Mass(MassUnit::Tonne),
+            Unit::Mass(MassUnit::Carat),
 
+            Unit::Mass(MassUnit::Grain),
+            Unit::Mass(MassUnit::Dram),
             Unit::Mass(MassUnit::Ounce),
             Unit::Mass(MassUnit::Pound),
             Unit::Mass(MassUnit::Stone),
+            Unit::Mass(MassUnit::ShortHundredweight),
+            Unit::Mass(MassUnit::LongHundredweight),
+            Unit::Mass(MassUnit::ShortTon),
+            Unit::Mass(MassUnit::LongTon),
+
+            Unit::Mass(MassUnit::Pennyweight),
+            Unit::Mass(MassUnit::TroyOunce),
+            Unit::Mass(MassUnit::TroyPound),
+
+            Unit::Mass(MassUnit::Scruple),
+            Unit::Mass(MassUnit::ApothecaryDram),
+
+            Unit::Mass(MassUnit::Slug),
+        ],
+
+        Unit::Speed(_) => vec![
+            Unit::Speed(SpeedUnit::MeterPerSecond(0)),
+            Unit::Speed(SpeedUnit::MeterPerSecond(3)),
+
+            Unit::Speed(SpeedUnit::KilometerPerHour),
+            Unit::Speed(SpeedUnit::MilePerHour),
+            Unit::Speed(SpeedUnit::FootPerSecond),
+            Unit::Speed(SpeedUnit::Knot),
+            Unit::Speed(SpeedUnit::Mach),
+            Unit::Speed(SpeedUnit::SpeedOfLight),
+        ],
+
+        Unit::Time(_) => vec![
+            Unit::Time(TimeUnit::Second(-9)),
+            Unit::Time(TimeUnit::Second(-6)),
+            Unit::Time(TimeUnit::Second(-3)),
+            Unit::Time(TimeUnit::Second(0)),
+
+            Unit::Time(TimeUnit::Minute),
+            Unit::Time(TimeUnit::Hour),
+            Unit::Time(TimeUnit::Day),
+            Unit::Time(TimeUnit::Week),
+            Unit::Time(TimeUnit::JulianYear),
+        ],
+
+        Unit::Pressure(_) => vec![
+            Unit::Pressure(PressureUnit::Pascal(0)),
+            Unit::Pressure(PressureUnit::Pascal(2)),
+            Unit::Pressure(PressureUnit::Pascal(3)),
+            Unit::Pressure(PressureUnit::Pascal(6)),
+            Unit::Pressure(PressureUnit::Bar(-3)),
+            Unit::Pressure(PressureUnit::Bar(0)),
+
+            Unit::Pressure(PressureUnit::Atmosphere),
+            Unit::Pressure(PressureUnit::Psi),
+            Unit::Pressure(PressureUnit::Psig),
+            Unit::Pressure(PressureUnit::MillimeterOfMercury),
+            Unit::Pressure(PressureUnit::InchOfMercury),
+            Unit::Pressure(PressureUnit::Torr),
+            Unit::Pressure(PressureUnit::KilogramForcePerCm2),
+        ],
+
+        Unit::Energy(_) => vec![
+            Unit::Energy(EnergyUnit::Joule(0)),
+            Unit::Energy(EnergyUnit::Joule(3)),
+            Unit::Energy(EnergyUnit::Joule(6)),
+            Unit::Energy(EnergyUnit::WattHour(0)),
+            Unit::Energy(EnergyUnit::WattHour(3)),
+            Unit::Energy(EnergyUnit::Calorie(0)),
+            Unit::Energy(EnergyUnit::Calorie(3)),
+            Unit::Energy(EnergyUnit::Electronvolt(0)),
+
+            Unit::Energy(EnergyUnit::CalorieIt),
+            Unit::Energy(EnergyUnit::KilocalorieIt),
+            Unit::Energy(EnergyUnit::FoodCalorie),
+            Unit::Energy(EnergyUnit::BtuIt),
+            Unit::Energy(EnergyUnit::BtuThermochemical),
+            Unit::Energy(EnergyUnit::Erg),
+            Unit::Energy(EnergyUnit::Therm),
+            Unit::Energy(EnergyUnit::FootPound),
+        ],
+
+        Unit::Power(_) => vec![
+            Unit::Power(PowerUnit::Watt(-3)),
+            Unit::Power(PowerUnit::Watt(0)),
+            Unit::Power(PowerUnit::Watt(3)),
+            Unit::Power(PowerUnit::Watt(6)),
+
+            Unit::Power(PowerUnit::HorsepowerMechanical),
+            Unit::Power(PowerUnit::HorsepowerMetric),
+            Unit::Power(PowerUnit::HorsepowerElectrical),
+            Unit::Power(PowerUnit::HorsepowerBoiler),
+            Unit::Power(PowerUnit::BtuPerHour),
+            Unit::Power(PowerUnit::TonOfRefrigeration),
+            Unit::Power(PowerUnit::ErgPerSecond),
+            Unit::Power(PowerUnit::DecibelMilliwatt),
+            Unit::Power(PowerUnit::DecibelWatt),
+        ],
+
+        Unit::Data(_) => vec![
+            Unit::Data(DataUnit::Bit(0)),
+            Unit::Data(DataUnit::Bit(3)),
+            Unit::Data(DataUnit::Bit(6)),
+            Unit::Data(DataUnit::Byte(0)),
+            Unit::Data(DataUnit::Byte(3)),
+            Unit::Data(DataUnit::Byte(6)),
+            Unit::Data(DataUnit::Byte(9)),
+            Unit::Data(DataUnit::Byte(12)),
+            Unit::Data(DataUnit::BinaryByte(10)),
+            Unit::Data(DataUnit::BinaryByte(20)),
+            Unit::Data(DataUnit::BinaryByte(30)),
+            Unit::Data(DataUnit::BinaryByte(40)),
+
+            Unit::Data(DataUnit::Nibble),
+        ],
+
+        Unit::DataRate(_) => vec![
+            Unit::DataRate(DataRateUnit::BitPerSecond(0)),
+            Unit::DataRate(DataRateUnit::BitPerSecond(3)),
+            Unit::DataRate(DataRateUnit::BitPerSecond(6)),
+            Unit::DataRate(DataRateUnit::BitPerSecond(9)),
+            Unit::DataRate(DataRateUnit::BytePerSecond(0)),
+            Unit::DataRate(DataRateUnit::BytePerSecond(3)),
+            Unit::DataRate(DataRateUnit::BytePerSecond(6)),
+            Unit::DataRate(DataRateUnit::BinaryBytePerSecond(10)),
+            Unit::DataRate(DataRateUnit::BinaryBytePerSecond(20)),
+        ],
+
+        Unit::Angle(_) => vec![
+            Unit::Angle(AngleUnit::Radian(-3)),
+            Unit::Angle(AngleUnit::Radian(0)),
+
+            Unit::Angle(AngleUnit::Degree),
+            Unit::Angle(AngleUnit::Arcminute),
+            Unit::Angle(AngleUnit::Arcsecond),
+            Unit::Angle(AngleUnit::Gradian),
+            Unit::Angle(AngleUnit::Turn),
+            Unit::Angle(AngleUnit::MilNato),
+            Unit::Angle(AngleUnit::MilWarsawPact),
+            Unit::Angle(AngleUnit::MilSwedish),
+        ],
+
+        Unit::SolidAngle(_) => vec![
+            Unit::SolidAngle(SolidAngleUnit::Steradian(-3)),
+            Unit::SolidAngle(SolidAngleUnit::Steradian(0)),
+
+            Unit::SolidAngle(SolidAngleUnit::SquareDegree),
+        ],
+
+        Unit::Force(_) => vec![
+            Unit::Force(ForceUnit::Newton(-3)),
+            Unit::Force(ForceUnit::Newton(0)),
+            Unit::Force(ForceUnit::Newton(3)),
+
+            Unit::Force(ForceUnit::Dyne),
+            Unit::Force(ForceUnit::KilogramForce),
+            Unit::Force(ForceUnit::PoundForce),
+            Unit::Force(ForceUnit::Poundal),
+            Unit::Force(ForceUnit::Kip),
+        ],
+
+        Unit::Torque(_) => vec![
+            Unit::Torque(TorqueUnit::NewtonMeter(0)),
+            Unit::Torque(TorqueUnit::NewtonMeter(3)),
+
+            Unit::Torque(TorqueUnit::PoundForceFoot),
+            Unit::Torque(TorqueUnit::PoundForceInch),
+            Unit::Torque(TorqueUnit::KilogramForceMeter),
+        ],
+
+        Unit::Frequency(_) => vec![
+            Unit::Frequency(FrequencyUnit::Hertz(0)),
+            Unit::Frequency(FrequencyUnit::Hertz(3)),
+            Unit::Frequency(FrequencyUnit::Hertz(6)),
+            Unit::Frequency(FrequencyUnit::Hertz(9)),
+
+            Unit::Frequency(FrequencyUnit::RevolutionPerMinute),
+            Unit::Frequency(FrequencyUnit::RadianPerSecond),
+            Unit::Frequency(FrequencyUnit::CyclePerMinute),
+            Unit::Frequency(FrequencyUnit::BeatPerMinute),
+        ],
+
+        Unit::FuelEconomy(_) => vec![
+            Unit::FuelEconomy(FuelEconomyUnit::LitersPer100Km),
+            Unit::FuelEconomy(FuelEconomyUnit::KilometersPerLiter),
+            Unit::FuelEconomy(FuelEconomyUnit::MilesPerGallonUs),
+            Unit::FuelEconomy(FuelEconomyUnit::MilesPerGallonUk),
+        ],
+
+        Unit::Density(_) => vec![
+            Unit::Density(DensityUnit::MassPerVolume(MassUnit::Gram(3), VolUnit::Meter3(0))),
+            Unit::Density(DensityUnit::MassPerVolume(MassUnit::Gram(0), VolUnit::Meter3(-2))),
+            Unit::Density(DensityUnit::MassPerVolume(MassUnit::Gram(0), VolUnit::Liter(-3))),
+            Unit::Density(DensityUnit::MassPerVolume(MassUnit::Gram(3), VolUnit::Liter(0))),
+            Unit::Density(DensityUnit::MassPerVolume(MassUnit::Pound, VolUnit::Feet3)),
+            Unit::Density(DensityUnit::MassPerVolume(MassUnit::Pound, VolUnit::Gallon(VolumeSystem::UsLiquid))),
+
+            Unit::Density(DensityUnit::SpecificGravity),
+        ],
+
+        Unit::Concentration(ConcentrationUnit::MolePerLiter(_)) => vec![
+            Unit::Concentration(ConcentrationUnit::MolePerLiter(0)),
+            Unit::Concentration(ConcentrationUnit::MolePerLiter(-3)),
+            Unit::Concentration(ConcentrationUnit::MolePerLiter(-6)),
+        ],
+        Unit::Concentration(_) => vec![
+            Unit::Density(DensityUnit::MassPerVolume(MassUnit::Gram(0), VolUnit::Liter(0))),
+            Unit::Density(DensityUnit::MassPerVolume(MassUnit::Gram(-3), VolUnit::Liter(0))),
+            Unit::Density(DensityUnit::MassPerVolume(MassUnit::Gram(-6), VolUnit::Liter(0))),
+
+            Unit::Concentration(ConcentrationUnit::PartsPerMillion),
+            Unit::Concentration(ConcentrationUnit::PartsPerBillion),
+            Unit::Concentration(ConcentrationUnit::PercentWeightPerVolume),
+        ],
+
+        Unit::FlowRate(_) => vec![
+            Unit::FlowRate(FlowRateUnit::VolumePerTime(VolUnit::Meter3(0), TimeUnit::Second(0))),
+            Unit::FlowRate(FlowRateUnit::VolumePerTime(VolUnit::Meter3(0), TimeUnit::Hour)),
+            Unit::FlowRate(FlowRateUnit::VolumePerTime(VolUnit::Liter(0), TimeUnit::Second(0))),
+            Unit::FlowRate(FlowRateUnit::VolumePerTime(VolUnit::Liter(0), TimeUnit::Minute)),
+            Unit::FlowRate(FlowRateUnit::VolumePerTime(VolUnit::Liter(0), TimeUnit::Hour)),
+            Unit::FlowRate(FlowRateUnit::VolumePerTime(VolUnit::Gallon(VolumeSystem::UsLiquid), TimeUnit::Minute)),
+            Unit::FlowRate(FlowRateUnit::VolumePerTime(VolUnit::Feet3, TimeUnit::Minute)),
+            Unit::FlowRate(FlowRateUnit::VolumePerTime(VolUnit::Barrel, TimeUnit::Day)),
+        ],
+
+        Unit::MassFlow(_) => vec![
+            Unit::MassFlow(MassFlowUnit::MassPerTime(MassUnit::Gram(0), TimeUnit::Second(0))),
+            Unit::MassFlow(MassFlowUnit::MassPerTime(MassUnit::Gram(3), TimeUnit::Second(0))),
+            Unit::MassFlow(MassFlowUnit::MassPerTime(MassUnit::Gram(3), TimeUnit::Hour)),
+            Unit::MassFlow(MassFlowUnit::MassPerTime(MassUnit::Pound, TimeUnit::Second(0))),
+            Unit::MassFlow(MassFlowUnit::MassPerTime(MassUnit::Pound, TimeUnit::Hour)),
+        ],
+
+        Unit::Charge(_) => vec![
+            Unit::Charge(ChargeUnit::Coulomb(-6)),
+            Unit::Charge(ChargeUnit::Coulomb(-3)),
+            Unit::Charge(ChargeUnit::Coulomb(0)),
+
+            Unit::Charge(ChargeUnit::AmpereHour(-3)),
+            Unit::Charge(ChargeUnit::AmpereHour(0)),
+        ],
+
+        Unit::Current(_) => vec![
+            Unit::Current(CurrentUnit::Ampere(-6)),
+            Unit::Current(CurrentUnit::Ampere(-3)),
+            Unit::Current(CurrentUnit::Ampere(0)),
+            Unit::Current(CurrentUnit::Ampere(3)),
+        ],
+
+        Unit::Voltage(_) => vec![
+            Unit::Voltage(VoltageUnit::Volt(-6)),
+            Unit::Voltage(VoltageUnit::Volt(-3)),
+            Unit::Voltage(VoltageUnit::Volt(0)),
+            Unit::Voltage(VoltageUnit::Volt(3)),
+        ],
+
+        Unit::Resistance(_) => vec![
+            Unit::Resistance(ResistanceUnit::Ohm(-3)),
+            Unit::Resistance(ResistanceUnit::Ohm(0)),
+            Unit::Resistance(ResistanceUnit::Ohm(3)),
+            Unit::Resistance(ResistanceUnit::Ohm(6)),
+        ],
+
+        Unit::Conductance(_) => vec![
+            Unit::Conductance(ConductanceUnit::Siemens(-6)),
+            Unit::Conductance(ConductanceUnit::Siemens(-3)),
+            Unit::Conductance(ConductanceUnit::Siemens(0)),
+        ],
+
+        Unit::Capacitance(_) => vec![
+            Unit::Capacitance(CapacitanceUnit::Farad(-12)),
+            Unit::Capacitance(CapacitanceUnit::Farad(-9)),
+            Unit::Capacitance(CapacitanceUnit::Farad(-6)),
+            Unit::Capacitance(CapacitanceUnit::Farad(-3)),
+            Unit::Capacitance(CapacitanceUnit::Farad(0)),
+        ],
+
+        Unit::Inductance(_) => vec![
+            Unit::Inductance(InductanceUnit::Henry(-9)),
+            Unit::Inductance(InductanceUnit::Henry(-6)),
+            Unit::Inductance(InductanceUnit::Henry(-3)),
+            Unit::Inductance(InductanceUnit::Henry(0)),
+        ],
+
+        Unit::MagneticFluxDensity(_) => vec![
+            Unit::MagneticFluxDensity(MagneticFluxDensityUnit::Tesla(-6)),
+            Unit::MagneticFluxDensity(MagneticFluxDensityUnit::Tesla(-3)),
+            Unit::MagneticFluxDensity(MagneticFluxDensityUnit::Tesla(0)),
+
+            Unit::MagneticFluxDensity(MagneticFluxDensityUnit::Gauss),
+        ],
+
+        Unit::MagneticFlux(_) => vec![
+            Unit::MagneticFlux(MagneticFluxUnit::Weber(-6)),
+            Unit::MagneticFlux(MagneticFluxUnit::Weber(-3)),
+            Unit::MagneticFlux(MagneticFluxUnit::Weber(0)),
+        ],
+
+        Unit::LuminousIntensity(_) => vec![
+            Unit::LuminousIntensity(LuminousIntensityUnit::Candela(-3)),
+            Unit::LuminousIntensity(LuminousIntensityUnit::Candela(0)),
+            Unit::LuminousIntensity(LuminousIntensityUnit::Candela(3)),
+        ],
+
+        Unit::LuminousFlux(_) => vec![
+            Unit::LuminousFlux(LuminousFluxUnit::Lumen(0)),
+            Unit::LuminousFlux(LuminousFluxUnit::Lumen(3)),
+        ],
+
+        Unit::Illuminance(_) => vec![
+            Unit::Illuminance(IlluminanceUnit::Lux(0)),
+            Unit::Illuminance(IlluminanceUnit::Lux(3)),
+
+            Unit::Illuminance(IlluminanceUnit::FootCandle),
+            Unit::Illuminance(IlluminanceUnit::Phot),
+        ],
+
+        Unit::Luminance(_) => vec![
+            Unit::Luminance(LuminanceUnit::Nit),
+            Unit::Luminance(LuminanceUnit::FootLambert),
+        ],
+
+        Unit::Radioactivity(_) => vec![
+            Unit::Radioactivity(RadioactivityUnit::Becquerel(0)),
+            Unit::Radioactivity(RadioactivityUnit::Becquerel(3)),
+            Unit::Radioactivity(RadioactivityUnit::Becquerel(6)),
+            Unit::Radioactivity(RadioactivityUnit::Becquerel(9)),
+
+            Unit::Radioactivity(RadioactivityUnit::Curie(-12)),
+            Unit::Radioactivity(RadioactivityUnit::Curie(-6)),
+            Unit::Radioactivity(RadioactivityUnit::Curie(-3)),
+            Unit::Radioactivity(RadioactivityUnit::Curie(0)),
+            Unit::Radioactivity(RadioactivityUnit::Rutherford),
+            Unit::Radioactivity(RadioactivityUnit::DisintegrationPerMinute),
+        ],
+
+        Unit::AbsorbedDose(_) => vec![
+            Unit::AbsorbedDose(AbsorbedDoseUnit::Gray(-6)),
+            Unit::AbsorbedDose(AbsorbedDoseUnit::Gray(-3)),
+            Unit::AbsorbedDose(AbsorbedDoseUnit::Gray(0)),
+
+            Unit::AbsorbedDose(AbsorbedDoseUnit::Rad(-3)),
+            Unit::AbsorbedDose(AbsorbedDoseUnit::Rad(0)),
+        ],
+
+        Unit::EquivalentDose(_) => vec![
+            Unit::EquivalentDose(EquivalentDoseUnit::Sievert(-6)),
+            Unit::EquivalentDose(EquivalentDoseUnit::Sievert(-3)),
+            Unit::EquivalentDose(EquivalentDoseUnit::Sievert(0)),
+
+            Unit::EquivalentDose(EquivalentDoseUnit::Rem(-3)),
+            Unit::EquivalentDose(EquivalentDoseUnit::Rem(0)),
+        ],
+
+        Unit::Acceleration(_) => vec![
+            Unit::Acceleration(AccelerationUnit::MeterPerSecond2(-3)),
+            Unit::Acceleration(AccelerationUnit::MeterPerSecond2(0)),
+
+            Unit::Acceleration(AccelerationUnit::FootPerSecond2),
+            Unit::Acceleration(AccelerationUnit::StandardGravity),
+            Unit::Acceleration(AccelerationUnit::Gal(0)),
+            Unit::Acceleration(AccelerationUnit::Gal(-3)),
+        ],
+
+        Unit::Amount(_) => vec![
+            Unit::Amount(AmountUnit::Mole(-6)),
+            Unit::Amount(AmountUnit::Mole(-3)),
+            Unit::Amount(AmountUnit::Mole(0)),
+
+            Unit::Amount(AmountUnit::Particle),
         ]
     }
 }
 
-pub fn convert_and_print_all(value: f32, a: Unit) {
-    let str_a = unit_to_string(a);
-    println!("{value} {str_a} equals to...");
+pub fn convert_and_print_all(value: f64, a: Unit) -> Result<(), ConversionError> {
+    ConversionContext::default().convert_and_print_all(value, a)
+}
+
+/// The system a mass unit belongs to, which [`convert_and_print_all`]
+/// groups its output by so that e.g. troy and avoirdupois ounces are not
+/// confused.
+fn mass_system(unit: Unit) -> Option<&'static str> {
+    let Unit::Mass(mass) = unit else {
+        return None;
+    };
+    let system = match mass {
+        MassUnit::Gram(_) | MassUnit::Tonne | MassUnit::Carat => "Metric",
+        MassUnit::Grain
+        | MassUnit::Dram
+        | MassUnit::Ounce
+        | MassUnit::Pound
+        | MassUnit::Stone
+        | MassUnit::ShortHundredweight
+        | MassUnit::LongHundredweight
+        | MassUnit::ShortTon
+        | MassUnit::LongTon => "Avoirdupois",
+        MassUnit::Pennyweight | MassUnit::TroyOunce | MassUnit::TroyPound => "Troy",
+        MassUnit::Scruple | MassUnit::ApothecaryDram => "Apothecaries'",
+        MassUnit::Slug => "Engineering",
+    };
+    Some(system)
+}
 
-    let units = fetch_all_units(a);
-    for unit in units {
-        if unit == a {
-            continue;
-        } else {
-            let str_b = unit_to_string(unit);
-            let converted = convert(value, a, unit);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-            println!("\t {converted} {str_b}");
+    #[test]
+    fn test_all_units_round_trip() {
+        // One unit of each kind that convert_and_print_all lists
+        let symbols = [
+            "K", "m", "m2", "L", "g", "m/s", "s", "Pa", "J", "W", "B", "B/s", "rad", "sr", "N", "N·m", "Hz",
+            "mpg", "kg/m3", "mol/L", "ppm", "L/s", "kg/s", "mAh", "A", "V", "Ω", "S", "µF", "H", "T", "Wb", "cd",
+            "lm", "lx", "cd/m2", "Bq", "Gy", "Sv", "m/s2", "mol",
+        ];
+        for symbol in symbols {
+            let sample = symbol.parse::<Unit>().unwrap();
+            for unit in fetch_all_units(sample) {
+                let symbol = format!("{unit:#}");
+                assert_eq!(symbol.parse(), Ok(unit), "symbol '{symbol}'");
+                let name = unit.to_string();
+                assert_eq!(name.parse(), Ok(unit), "name '{name}'");
+            }
         }
     }
 }
@@ -1,24 +1,39 @@
 use std::env;
-use unit_converter::{AreaUnit, LengthUnit, MassUnit, TempUnit, Unit, VolUnit};
-use unit_converter::{convert_and_print_to, convert_and_print_all, convert};
-use unit_converter::TempUnit::{Celsius, Kelvin};
+use std::process;
+use unit_converter::{molar_mass, parse_dms, parse_duration, ConversionContext, Dimension, Ratio, Unit};
 
 enum Task {
     Error(String),
     Help,
     DisplayUnits,
-    ConvertTo(f32, Unit, Unit),
-    ConvertAll(f32, Unit),
+    ConvertTo(f64, Unit, Unit),
+    ConvertAll(f64, Unit),
+    ConvertToDuration(f64, Unit),
+    ConvertWavelength(f64, Unit, Unit),
+    /// Value, units, and a chemical formula with its molar mass.
+    ConvertSubstance(f64, Unit, Unit, String, Ratio),
 }
 
 fn print_help() {
     println!("USAGE:");
     println!("  -h, --help              Display this help message");
+    println!("  --dpi [value]           Resolution for pixels in dots per inch, 96 by default");
+    println!("  --font-size [value]     Font size for ems in points, 12 by default");
     println!("  units                   Display all available units");
     println!("  [unit]                  Convert 1.0 in an unit to all other possible units");
     println!("  [value] [unit]          Convert a value in an unit to all other possible units");
     println!("  [unit] [unit]           Convert a 1.0 in unit A to unit B");
     println!("  [value] [unit] [unit]   Convert a value in unit A to unit B");
+    println!("  ... compound            Print a time as a compound duration, e.g. 5400 s compound");
+    println!("  ... wave                Convert between wavelength and frequency, e.g. 500 nm THz wave");
+    println!("  [value] [unit] [formula] [unit]");
+    println!("                          Convert between mass and amount of a substance, e.g.");
+    println!("                          12 g C6H12O6 mmol, or 12 g C6H12O6 to mmol");
+    println!();
+    println!("A duration such as 1h30m, \"2d 4h 30m\" or PT1H30M can replace [value] [unit].");
+    println!("In durations, m means minutes.");
+    println!("An angle in degrees, minutes and seconds such as 12°30'15\" can also replace");
+    println!("[value] [unit]; quote it for the shell.");
 }
 
 fn display_units() {
@@ -36,6 +51,28 @@ fn display_units() {
     println!("    ft, feet");
     println!("    yd, yards");
     println!("    mi, miles");
+    println!("    mil, thou, thousandths of an inch");
+    println!("    micron, microns (micrometers)");
+    println!("    nmi, nautical miles");
+    println!("    ftm, fathoms");
+    println!("    li, links");
+    println!("    rd, rods (lower case; Rd is the rutherford)");
+    println!("    ch, chains");
+    println!("    fur, furlongs");
+    println!("    lea, leagues");
+    println!("    ft_US, survey ft, US survey feet (1200/3937 m)");
+    println!("    mi_US, survey mi, US survey miles");
+    println!("    Å, angstroms");
+    println!("    au, astronomical units");
+    println!("    ly, light-years");
+    println!("    pc, parsecs");
+    println!("    pt_DTP, points (pt is a pint unless converting to a length)");
+    println!("    pica, picas");
+    println!("    dd, Didot points");
+    println!("    cicero, ciceros");
+    println!("    twip, twips");
+    println!("    em, ems (see --font-size)");
+    println!("    px, pixels (see --dpi)");
 
     println!("AREA");
     println!("    mm2, square millimeters");
@@ -55,72 +92,393 @@ fn display_units() {
     println!("    mm3, cubic millimeters");
     println!("    cm3, cubic centimeters");
     println!("    m3, cubic meters");
-    println!("    teaspoons");
-    println!("    tablespoons");
-    println!("    cups");
+    println!("    km3, cubic kilometers");
+    println!("    tsp, teaspoons (metric, 5 mL)");
+    println!("    tbsp, tablespoons (metric, 15 mL)");
+    println!("    fl oz, fluid ounces");
+    println!("    gi, gills");
+    println!("    cup, cups");
     println!("    pt, pints");
+    println!("    qt, quarts");
     println!("    gal, gallons");
+    println!("    pk, pecks (US dry)");
+    println!("    bu, bushels (US dry)");
+    println!("    in3, cubic inches");
+    println!("    ft3, cu ft, cubic feet");
+    println!("    yd3, cubic yards");
+    println!("    bbl, barrels (oil)");
+    println!("    customary volumes are US liquid unless qualified by a system:");
+    println!("    us, us dry, uk or imperial, metric and au, e.g. uk gal, us dry pt,");
+    println!("    us tbsp, metric cup, au tbsp (20 mL); or as a suffix, e.g. gal_UK");
 
     println!("WEIGHT");
     println!("    mg, milligrams");
     println!("    g, grams");
     println!("    kg, kilograms");
-    println!("    oz, ounces");
-    println!("    lb, pounds");
+    println!("    ct, carats (metric, 200 mg)");
+    println!("    gr, grains");
+    println!("    dr, drams (avoirdupois)");
+    println!("    oz, ounces (avoirdupois)");
+    println!("    lb, pounds (avoirdupois)");
     println!("    st, stones");
+    println!("    cwt, us cwt, cwt_US, short hundredweights (100 lb)");
+    println!("    uk cwt, cwt_UK, long hundredweights (112 lb)");
+    println!("    ton, us ton, ton_US, short tons (2000 lb)");
+    println!("    uk ton, ton_UK, long tons (2240 lb)");
+    println!("    t, metric ton, tonnes");
+    println!("    dwt, pennyweights");
+    println!("    ozt, troy oz, troy ounces");
+    println!("    lbt, troy lb, troy pounds");
+    println!("    s_ap, ℈, scruples (apothecaries')");
+    println!("    dr_ap, ʒ, apothecaries' drams");
+    println!("    slug, slugs");
+
+    println!("SPEED");
+    println!("    m/s, meters per second");
+    println!("    km/s, kilometers per second");
+    println!("    km/h, kph, kilometers per hour");
+    println!("    mph, miles per hour");
+    println!("    ft/s, fps, feet per second");
+    println!("    kn, kt, knots");
+    println!("    Ma, mach (at sea level)");
+    println!("    c0, speed of light");
+
+    println!("TIME");
+    println!("    ns, nanoseconds");
+    println!("    µs, us, microseconds");
+    println!("    ms, milliseconds");
+    println!("    s, sec, seconds");
+    println!("    min, minutes");
+    println!("    h, hr, hours");
+    println!("    d, days");
+    println!("    wk, weeks");
+    println!("    yr, julian years");
+
+    println!("PRESSURE");
+    println!("    Pa, pascals");
+    println!("    hPa, hectopascals");
+    println!("    kPa, kilopascals");
+    println!("    MPa, megapascals");
+    println!("    mbar, millibars");
+    println!("    bar, bars");
+    println!("    atm, atmospheres");
+    println!("    psi, pounds per square inch");
+    println!("    psig, pounds per square inch gauge (relative to 1 atm)");
+    println!("    mmHg, millimeters of mercury");
+    println!("    inHg, inches of mercury");
+    println!("    Torr, torr");
+    println!("    kgf/cm2, at, kilograms-force per square centimeter");
+
+    println!("ENERGY");
+    println!("    J, joules");
+    println!("    kJ, kilojoules");
+    println!("    MJ, megajoules");
+    println!("    Wh, watt-hours");
+    println!("    kWh, kilowatt-hours");
+    println!("    cal, calories (thermochemical)");
+    println!("    kcal, kilocalories (thermochemical)");
+    println!("    Cal, food calories");
+    println!("    cal_IT, IT calories");
+    println!("    kcal_IT, IT kilocalories");
+    println!("    BTU, british thermal units");
+    println!("    BTU_th, thermochemical british thermal units");
+    println!("    eV, electronvolts");
+    println!("    erg, ergs");
+    println!("    thm, therms");
+    println!("    ft-lbf, foot-pounds");
+
+    println!("POWER");
+    println!("    W, watts");
+    println!("    kW, kilowatts");
+    println!("    MW, megawatts");
+    println!("    hp, mechanical horsepower");
+    println!("    hp(M), PS, metric horsepower (PS alone is the petasiemens)");
+    println!("    hp(E), electrical horsepower");
+    println!("    hp(S), boiler horsepower");
+    println!("    BTU/h, british thermal units per hour");
+    println!("    TR, tons of refrigeration");
+    println!("    erg/s, ergs per second");
+    println!("    dBm, decibel-milliwatts");
+    println!("    dBW, decibel-watts");
+
+    println!("DATA");
+    println!("    bit, b, bits");
+    println!("    kbit, kb, kilobits");
+    println!("    Mbit, Mb, megabits");
+    println!("    B, bytes");
+    println!("    kB, KB, kilobytes");
+    println!("    MB, megabytes");
+    println!("    GB, gigabytes");
+    println!("    TB, terabytes");
+    println!("    KiB, kibibytes");
+    println!("    MiB, mebibytes");
+    println!("    GiB, gibibytes");
+    println!("    TiB, tebibytes");
+    println!("    nibble, nibbles");
+
+    println!("DATA RATE");
+    println!("    bit/s, bps, bits per second");
+    println!("    kbit/s, kbps, kilobits per second");
+    println!("    Mbit/s, Mbps, megabits per second");
+    println!("    Gbit/s, Gbps, gigabits per second");
+    println!("    B/s, bytes per second");
+    println!("    kB/s, kilobytes per second");
+    println!("    MB/s, megabytes per second");
+    println!("    KiB/s, kibibytes per second");
+    println!("    MiB/s, mebibytes per second");
+
+    println!("ANGLE");
+    println!("    rad, radians");
+    println!("    mrad, milliradians");
+    println!("    deg, °, degrees");
+    println!("    arcmin, arcminutes");
+    println!("    arcsec, arcseconds");
+    println!("    gon, grad, gradians");
+    println!("    turn, rev, turns");
+    println!("    mil_NATO, NATO mils (1/6400 turn)");
+    println!("    mil_WP, Warsaw Pact mils (1/6000 turn)");
+    println!("    mil_SE, streck, Swedish mils (1/6300 turn)");
+
+    println!("SOLID ANGLE");
+    println!("    sr, steradians");
+    println!("    msr, millisteradians");
+    println!("    deg2, square degrees");
+
+    println!("FORCE");
+    println!("    N, newtons");
+    println!("    kN, kilonewtons");
+    println!("    dyn, dynes");
+    println!("    kgf, kp, kilograms-force");
+    println!("    lbf, pounds-force");
+    println!("    pdl, poundals");
+    println!("    kip, kips");
+
+    println!("TORQUE");
+    println!("    N·m, Nm, newton-meters");
+    println!("    kN·m, kilonewton-meters");
+    println!("    lbf·ft, lbf-ft, pound-force feet");
+    println!("    lbf·in, lbf-in, pound-force inches");
+    println!("    kgf·m, kgf-m, kilogram-force meters");
+
+    println!("FREQUENCY");
+    println!("    Hz, hertz");
+    println!("    kHz, kilohertz");
+    println!("    MHz, megahertz");
+    println!("    GHz, gigahertz");
+    println!("    rpm, revolutions per minute");
+    println!("    rad/s, radians per second");
+    println!("    cpm, cycles per minute");
+    println!("    bpm, beats per minute");
+
+    println!("FUEL ECONOMY");
+    println!("    L/100km, liters per 100 kilometers");
+    println!("    km/L, kilometers per liter");
+    println!("    mpg, mpg_US, miles per US gallon");
+    println!("    mpg_UK, miles per imperial gallon");
+
+    println!("DENSITY");
+    println!("    kg/m3, kilograms per cubic meter");
+    println!("    g/cm3, grams per cubic centimeter");
+    println!("    g/mL, grams per milliliter");
+    println!("    lb/ft3, pounds per cubic foot");
+    println!("    lb/gal, pounds per gallon");
+    println!("    SG, specific gravity (water at 4 °C)");
+    println!("    any other mass unit over a volume unit, e.g. oz/in3");
+
+    println!("ELECTRICAL");
+    println!("    C, coulombs");
+    println!("    Ah, mAh, ampere-hours, milliampere-hours");
+    println!("    A, mA, amperes, amps");
+    println!("    V, mV, kV, volts");
+    println!("    Ω, ohm, kΩ, MΩ, ohms");
+    println!("    S, mS, siemens");
+    println!("    F, µF, nF, pF, farads");
+    println!("    H, mH, µH, henries");
+    println!("    T, mT, µT, teslas");
+    println!("    G, gauss");
+    println!("    Wb, webers");
+    println!("    C and F mean coulombs and farads when converting to or from");
+    println!("    another charge or capacitance, and degrees otherwise");
+
+    println!("PHOTOMETRY");
+    println!("    cd, mcd, candelas");
+    println!("    lm, lumens");
+    println!("    lx, klx, lux");
+    println!("    fc, foot-candles");
+    println!("    ph, phots");
+    println!("    nit, cd/m2, nits");
+    println!("    ftL, ft-L, foot-lamberts");
+
+    println!("RADIOACTIVITY");
+    println!("    Bq, kBq, MBq, GBq, becquerels");
+    println!("    Ci, mCi, µCi, pCi, curies");
+    println!("    Rd, rutherfords");
+    println!("    dpm, disintegrations per minute");
+
+    println!("ABSORBED DOSE");
+    println!("    Gy, mGy, grays");
+    println!("    rad_dose, mrad_dose, rads (plain rad means the radian unless");
+    println!("    converting to or from another absorbed dose)");
+
+    println!("EQUIVALENT DOSE");
+    println!("    Sv, mSv, µSv, sieverts");
+    println!("    rem, mrem, rems");
+
+    println!("ACCELERATION");
+    println!("    m/s2, m/s², meters per second squared");
+    println!("    ft/s2, feet per second squared");
+    println!("    g0, g₀, standard gravities");
+    println!("    Gal, mGal, µGal, gals (upper case G; gal is the gallon)");
+
+    println!("FLOW RATE");
+    println!("    m3/s, cubic meters per second");
+    println!("    L/min, liters per minute");
+    println!("    L/h, liters per hour");
+    println!("    gal/min, gpm, gallons per minute");
+    println!("    ft3/min, cfm, cubic feet per minute");
+    println!("    bbl/d, bpd, barrels per day");
+    println!("    any other volume unit over s, min, h, d, wk or yr");
+
+    println!("MASS FLOW");
+    println!("    kg/s, kilograms per second");
+    println!("    kg/h, kilograms per hour");
+    println!("    lb/h, pounds per hour");
+    println!("    any other mass unit over s, min, h, d, wk or yr");
+
+    println!("CONCENTRATION");
+    println!("    mol/L, moles per liter");
+    println!("    mmol/L, millimoles per liter");
+    println!("    mg/L, milligrams per liter (or any other density unit)");
+    println!("    ppm, parts per million");
+    println!("    ppb, parts per billion");
+    println!("    %w/v, percent weight per volume");
+
+    println!("AMOUNT OF SUBSTANCE");
+    println!("    mol, mmol, µmol, moles");
+    println!("    particles, molecules, atoms");
+    println!("    converts to a mass given a chemical formula, e.g. 12 g C6H12O6 mmol");
+
+    println!("SI PREFIXES");
+    println!("    m, m2, m3, L, g, m/s, s, Pa, bar, J, Wh, cal, eV, W, rad, sr, N, N·m, Hz, mol, mol/L, cd, lm, lx, Bq, Ci, Gy, Sv, rem, m/s2, Gal and the electrical units accept any SI prefix, by symbol or by name:");
+    println!("    q quecto, r ronto, y yocto, z zepto, a atto, f femto, p pico,");
+    println!("    n nano, µ/u micro, m milli, c centi, d deci, da deca, h hecto,");
+    println!("    k kilo, M mega, G giga, T tera, P peta, E exa, Z zetta, Y yotta,");
+    println!("    R ronna, Q quetta (e.g. µm, nL, Mg, hm2, megagrams)");
+    println!("    Prefix symbols are case-sensitive: Mg is a megagram, mg a milligram");
+
+    println!("BINARY PREFIXES");
+    println!("    bit, B, bit/s and B/s also accept positive SI prefixes and IEC binary prefixes:");
+    println!("    Ki kibi, Mi mebi, Gi gibi, Ti tebi, Pi pebi, Ei exbi, Zi zebi,");
+    println!("    Yi yobi (e.g. KiB, Mibit, GiB/s, kibibytes)");
 }
 
-pub fn parse_unit(token: &str) -> Option<Unit> {
-    match token.to_lowercase().as_str() {
-        // Temperature
-        "k" | "kelvin" => Some(Unit::Temperature(TempUnit::Kelvin)),
-        "c" | "celsius" => Some(Unit::Temperature(TempUnit::Celsius)),
-        "f" | "fahrenheit" => Some(Unit::Temperature(TempUnit::Fahrenheit)),
-
-        // Length
-        "mm" => Some(Unit::Length(LengthUnit::Meter(-3))),
-        "cm" => Some(Unit::Length(LengthUnit::Meter(-2))),
-        "m"  => Some(Unit::Length(LengthUnit::Meter(0))),
-        "km" => Some(Unit::Length(LengthUnit::Meter(3))),
-        "in" | "inch" | "inches" => Some(Unit::Length(LengthUnit::Inch)),
-        "ft" | "feet" => Some(Unit::Length(LengthUnit::Feet)),
-        "yd" | "yard" | "yards" => Some(Unit::Length(LengthUnit::Yard)),
-        "mi" | "mile" | "miles" => Some(Unit::Length(LengthUnit::Mile)),
-
-        // Area
-        "mm2" => Some(Unit::Area(AreaUnit::Meter2(-3))),
-        "cm2" => Some(Unit::Area(AreaUnit::Meter2(-2))),
-        "m2"  => Some(Unit::Area(AreaUnit::Meter2(0))),
-        "km2" => Some(Unit::Area(AreaUnit::Meter2(3))),
-        "in2" => Some(Unit::Area(AreaUnit::Inch2)),
-        "ft2" | "sqft" => Some(Unit::Area(AreaUnit::Feet2)),
-        "yd2" => Some(Unit::Area(AreaUnit::Yard2)),
-        "mi2" => Some(Unit::Area(AreaUnit::Mile2)),
-        "ac" | "acre" | "acres" => Some(Unit::Area(AreaUnit::Acre)),
-        "ha" | "hectare" | "hectares" => Some(Unit::Area(AreaUnit::Hectare)),
-
-        // Volume
-        "ml" => Some(Unit::Volume(VolUnit::Liter(-3))),
-        "l" | "liter" | "liters" => Some(Unit::Volume(VolUnit::Liter(0))),
-        "mm3" => Some(Unit::Volume(VolUnit::Meter3(-3))),
-        "cm3" => Some(Unit::Volume(VolUnit::Meter3(-2))),
-        "m3" => Some(Unit::Volume(VolUnit::Meter3(0))),
-        "teaspoon" | "teaspoons" => Some(Unit::Volume(VolUnit::TeaSpoon)),
-        "tablespoon" | "tablespoons" => Some(Unit::Volume(VolUnit::TableSpoon)),
-        "cup" | "cups" => Some(Unit::Volume(VolUnit::Cup)),
-        "pt" | "pint" | "pints" => Some(Unit::Volume(VolUnit::Pint)),
-        "gal" | "gallon" | "gallons" => Some(Unit::Volume(VolUnit::Gallon)),
-
-        // Mass
-        "mg" => Some(Unit::Mass(MassUnit::Gram(-3))),
-        "g"  | "gram" | "grams" => Some(Unit::Mass(MassUnit::Gram(0))),
-        "kg" => Some(Unit::Mass(MassUnit::Gram(3))),
-        "oz" | "ounce" | "ounces" => Some(Unit::Mass(MassUnit::Ounce)),
-        "lb" | "pound" | "pounds" => Some(Unit::Mass(MassUnit::Pound)),
-        "st" | "stone" | "stones" => Some(Unit::Mass(MassUnit::Stone)),
-
-        _ => None,
+/// Replaces a leading degrees-minutes-seconds angle such as `12°30'15"`
+/// with its value in degrees.
+fn expand_dms(tokens: Vec<String>) -> Vec<String> {
+    let Some(degrees) = tokens.first().and_then(|token| parse_dms(token).ok()) else {
+        return tokens;
+    };
+
+    let mut expanded = vec![degrees.to_string(), "deg".to_string()];
+    expanded.extend(tokens.into_iter().skip(1));
+    expanded
+}
+
+/// Replaces leading duration tokens ("1h30m", "2d 4h 30m" or "PT1H30M")
+/// with their value in seconds, unless a unit other than a time follows:
+/// "5m ft" is five meters, not five minutes.
+fn expand_duration(tokens: Vec<String>) -> Vec<String> {
+    let durations: Vec<f64> = tokens
+        .iter()
+        .map_while(|token| {
+            if token.parse::<f64>().is_ok() {
+                None
+            } else {
+                parse_duration(token).ok()
+            }
+        })
+        .collect();
+    if durations.is_empty() {
+        return tokens;
+    }
+    let target = tokens[durations.len()..].join(" ");
+    if !target.is_empty() && target.parse::<Unit>().map(|unit| unit.dimension()) != Ok(Dimension::Time) {
+        return tokens;
+    }
+
+    let seconds: f64 = durations.iter().sum();
+    let mut expanded = vec![seconds.to_string(), "s".to_string()];
+    expanded.extend(tokens.into_iter().skip(durations.len()));
+    expanded
+}
+
+/// Parses "[value] [unit] [formula] [unit]", converting between a mass and
+/// an amount of the substance `formula`.
+fn parse_substance(value: &str, a: &str, formula: &str, b: &str) -> Task {
+    let Ok(val) = value.parse::<f64>() else {
+        return Task::Error("First argument must be a number".to_string());
+    };
+    let Some((a, b)) = parse_pair(a, b) else {
+        return Task::Error("Invalid unit(s)".to_string());
+    };
+    // A formula only links a mass and an amount
+    match (a.dimension(), b.dimension()) {
+        (Dimension::Mass, Dimension::Amount) | (Dimension::Amount, Dimension::Mass) => {}
+        _ => return Task::Error("A formula converts between a mass and an amount of substance".to_string()),
+    }
+    match molar_mass(formula) {
+        Ok(mass) => Task::ConvertSubstance(val, a, b, formula.to_string(), mass),
+        Err(err) => Task::Error(err.to_string()),
+    }
+}
+
+/// Takes the "--dpi" and "--font-size" options out of `tokens`, wherever
+/// they are, into the context that conversions use.
+fn parse_options(tokens: Vec<String>) -> Result<(ConversionContext, Vec<String>), String> {
+    let mut context = ConversionContext::default();
+    let mut rest = Vec::with_capacity(tokens.len());
+    let mut tokens = tokens.into_iter();
+    while let Some(token) = tokens.next() {
+        let setting = match token.as_str() {
+            "--dpi" => &mut context.dpi,
+            "--font-size" => &mut context.font_size,
+            _ => {
+                rest.push(token);
+                continue;
+            }
+        };
+        *setting = tokens
+            .next()
+            .and_then(|value| value.parse::<Ratio>().ok())
+            .ok_or_else(|| format!("{token} needs a number"))?;
+    }
+    Ok((context, rest))
+}
+
+/// Joins words that only name a unit together, such as "uk gal" or
+/// "fl oz", so that they need no quotes.
+fn join_unit_words(tokens: Vec<String>) -> Vec<String> {
+    let mut joined: Vec<String> = Vec::new();
+    // From the right, so that "us dry pt" joins "dry pt" first
+    for token in tokens.into_iter().rev() {
+        match joined.last_mut() {
+            Some(next) if format!("{token} {next}").parse::<Unit>().is_ok() => {
+                *next = format!("{token} {next}");
+            }
+            _ => joined.push(token),
+        }
     }
+    joined.reverse();
+    joined
+}
+
+/// Parses a source and target unit, reading a symbol with several meanings
+/// in the dimension of the other: "47 uF F" is in farads, not Fahrenheit.
+fn parse_pair(a: &str, b: &str) -> Option<(Unit, Unit)> {
+    let a_unit = a.parse::<Unit>().ok()?;
+    let b_unit = Unit::parse_as(b, a_unit.dimension()).ok()?;
+    let a_unit = Unit::parse_as(a, b_unit.dimension()).ok()?;
+    Some((a_unit, b_unit))
 }
 
 fn parser(tokens: Vec<String>) -> Task {
@@ -128,6 +486,30 @@ fn parser(tokens: Vec<String>) -> Task {
         return Task::Help;
     }
 
+    // Handle compound duration output
+    if tokens.len() > 1 && tokens[tokens.len() - 1] == "compound" {
+        let mut tokens = tokens;
+        tokens.pop();
+        return match parser(tokens) {
+            Task::ConvertAll(val, unit) => Task::ConvertToDuration(val, unit),
+            Task::ConvertTo(..) => Task::Error("Too many arguments".to_string()),
+            task => task,
+        };
+    }
+
+    // Handle wavelength <-> frequency
+    if tokens.len() > 1 && tokens[tokens.len() - 1] == "wave" {
+        let mut tokens = tokens;
+        tokens.pop();
+        return match parser(tokens) {
+            Task::ConvertTo(val, a, b) => Task::ConvertWavelength(val, a, b),
+            Task::ConvertAll(..) => Task::Error("wave needs a target unit".to_string()),
+            task => task,
+        };
+    }
+
+    let tokens = join_unit_words(expand_dms(expand_duration(tokens)));
+
     // Handle help flags
     if tokens[0] == "-h" || tokens[0] == "--help" {
         return Task::Help;
@@ -140,26 +522,21 @@ fn parser(tokens: Vec<String>) -> Task {
 
     match tokens.len() {
         1 => {
-            if let Some(unit) = parse_unit(&tokens[0]) {
-                Task::ConvertAll(1.0, unit)
-            } else {
-                Task::Error(format!("Unknown unit '{}'", tokens[0]))
+            match tokens[0].parse::<Unit>() {
+                Ok(unit) => Task::ConvertAll(1.0, unit),
+                Err(err) => Task::Error(err.to_string()),
             }
         }
         2 => {
             // Case A: number + unit
-            if let Ok(val) = tokens[0].parse::<f32>() {
-                if let Some(unit) = parse_unit(&tokens[1]) {
-                    Task::ConvertAll(val, unit)
-                } else {
-                    Task::Error(format!("Unknown unit '{}'", tokens[1]))
+            if let Ok(val) = tokens[0].parse::<f64>() {
+                match tokens[1].parse::<Unit>() {
+                    Ok(unit) => Task::ConvertAll(val, unit),
+                    Err(err) => Task::Error(err.to_string()),
                 }
             } else {
                 // Case B: unit + unit
-                if let (Some(a), Some(b)) = (
-                    parse_unit(&tokens[0]),
-                    parse_unit(&tokens[1])
-                ) {
+                if let Some((a, b)) = parse_pair(&tokens[0], &tokens[1]) {
                     Task::ConvertTo(1.0, a, b)
                 } else {
                     Task::Error("Invalid unit(s)".to_string())
@@ -167,11 +544,8 @@ fn parser(tokens: Vec<String>) -> Task {
             }
         }
         3 => {
-            if let Ok(val) = tokens[0].parse::<f32>() {
-                if let (Some(a), Some(b)) = (
-                    parse_unit(&tokens[1]),
-                    parse_unit(&tokens[2])
-                ) {
+            if let Ok(val) = tokens[0].parse::<f64>() {
+                if let Some((a, b)) = parse_pair(&tokens[1], &tokens[2]) {
                     Task::ConvertTo(val, a, b)
                 } else {
                     Task::Error("Invalid unit(s)".to_string())
@@ -180,47 +554,56 @@ fn parser(tokens: Vec<String>) -> Task {
                 Task::Error("First argument must be a number".to_string())
             }
         }
+        // A formula in third place, or else too many arguments
+        4 if molar_mass(&tokens[2]).is_ok() => parse_substance(&tokens[0], &tokens[1], &tokens[2], &tokens[3]),
+        5 if tokens[3] == "to" => parse_substance(&tokens[0], &tokens[1], &tokens[2], &tokens[4]),
         _ => Task::Error("Too many arguments".to_string()),
     }
 }
 
 fn main() {
-    let args = env::args().skip(1);
-    let task = parser(args.collect());
-    match task {
+    let (context, args) = match parse_options(env::args().skip(1).collect()) {
+        Ok(options) => options,
+        Err(msg) => {
+            eprintln!("{msg}");
+            process::exit(1);
+        }
+    };
+    let task = parser(args);
+    let result = match task {
         Task::Error(msg) => {
-            println!("{msg}");
+            eprintln!("{msg}");
+            process::exit(1);
+        }
+        Task::Help => {
+            print_help();
+            Ok(())
+        }
+        Task::DisplayUnits => {
+            display_units();
+            Ok(())
         }
-        Task::Help => print_help(),
-        Task::DisplayUnits => display_units(),
-        Task::ConvertTo(value, a, b) => convert_and_print_to(value, a, b),
-        Task::ConvertAll(value, a) => convert_and_print_all(value, a),
+        Task::ConvertTo(value, a, b) => context.convert_and_print_to(value, a, b),
+        Task::ConvertAll(value, a) => context.convert_and_print_all(value, a),
+        Task::ConvertToDuration(value, a) => context.convert_and_print_duration(value, a),
+        Task::ConvertWavelength(value, a, b) => context.convert_and_print_wavelength(value, a, b),
+        Task::ConvertSubstance(value, a, b, formula, molar_mass) => {
+            context.convert_and_print_substance(value, a, b, &formula, molar_mass)
+        }
+    };
+
+    if let Err(err) = result {
+        eprintln!("{err}");
+        process::exit(1);
     }
-    println!("{}", convert(0.0, Unit::Temperature(Celsius), Unit::Temperature(Kelvin)));
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    #[test]
-    fn test_parse_units() {
-        assert_eq!(parse_unit("cm"), Some(Unit::Length(LengthUnit::Meter(-2))));
-        assert_eq!(parse_unit("km"), Some(Unit::Length(LengthUnit::Meter(3))));
-        assert_eq!(parse_unit("m"), Some(Unit::Length(LengthUnit::Meter(0))));
-
-        assert_eq!(parse_unit("teaspoon"), Some(Unit::Volume(VolUnit::TeaSpoon)));
-        assert_eq!(parse_unit("gal"), Some(Unit::Volume(VolUnit::Gallon)));
-
-        assert_eq!(parse_unit("F"), Some(Unit::Temperature(TempUnit::Fahrenheit)));
-    }
-
-    #[test]
-    fn test_parse_invalid_units() {
-        assert_eq!(parse_unit("ledsago"), None);
-        assert_eq!(parse_unit(""), None);
-        assert_eq!(parse_unit("newtons"), None);
-    }
+    use unit_converter::{AbsorbedDoseUnit, AngleUnit, CapacitanceUnit, ChargeUnit, FrequencyUnit, LengthUnit, TempUnit, TimeUnit};
+    use unit_converter::{AmountUnit, MassUnit};
+    use unit_converter::{CurrentUnit, VolUnit, VolumeSystem};
 
     #[test]
     fn test_parser_no_arg() {
@@ -320,4 +703,212 @@ mod tests {
             _ => panic!("Expected Task::Error for too many args"),
         }
     }
+
+    #[test]
+    fn test_parser_unit_words() {
+        let args = vec!["1", "uk", "gal", "us", "dry", "pt"].into_iter().map(String::from).collect();
+        if let Task::ConvertTo(val, a, b) = parser(args) {
+            assert_eq!(val, 1.0);
+            assert_eq!(a, Unit::Volume(VolUnit::Gallon(VolumeSystem::Imperial)));
+            assert_eq!(b, Unit::Volume(VolUnit::Pint(VolumeSystem::UsDry)));
+        } else {
+            panic!("Expected ConvertTo");
+        }
+
+        let args = vec!["3", "fl", "oz"].into_iter().map(String::from).collect();
+        assert!(matches!(parser(args), Task::ConvertAll(3.0, Unit::Volume(VolUnit::FluidOunce(_)))));
+        // "us" on its own is still the microsecond
+        let args = vec!["1", "us", "ms"].into_iter().map(String::from).collect();
+        assert!(matches!(parser(args), Task::ConvertTo(1.0, Unit::Time(_), Unit::Time(_))));
+    }
+
+    #[test]
+    fn test_parser_duration() {
+        // A compound duration stands in for value + unit
+        let args = vec!["1h30m".to_string(), "min".to_string()];
+        if let Task::ConvertTo(val, a, b) = parser(args) {
+            assert_eq!(val, 5400.0);
+            assert_eq!(a, Unit::Time(TimeUnit::Second(0)));
+            assert_eq!(b, Unit::Time(TimeUnit::Minute));
+        } else {
+            panic!("Expected ConvertTo");
+        }
+
+        // Unquoted components are joined
+        let args = vec!["2d".to_string(), "4h".to_string(), "30m".to_string()];
+        if let Task::ConvertAll(val, unit) = parser(args) {
+            assert_eq!(val, 189_000.0);
+            assert_eq!(unit, Unit::Time(TimeUnit::Second(0)));
+        } else {
+            panic!("Expected ConvertAll");
+        }
+
+        let args = vec!["PT1H30M".to_string(), "h".to_string()];
+        assert!(matches!(parser(args), Task::ConvertTo(5400.0, _, _)));
+
+        // Only before a time unit: "5m ft" is not five minutes
+        let args = vec!["5m".to_string(), "ft".to_string()];
+        assert!(matches!(parser(args), Task::Error(msg) if msg == "Invalid unit(s)"));
+        let args = vec!["5m".to_string(), "julian".to_string(), "years".to_string()];
+        assert!(matches!(parser(args), Task::ConvertTo(300.0, _, _)));
+    }
+
+    #[test]
+    fn test_parser_dms() {
+        // A degrees-minutes-seconds angle stands in for value + unit
+        let args = vec!["12°30'".to_string(), "gon".to_string()];
+        if let Task::ConvertTo(val, a, b) = parser(args) {
+            assert_eq!(val, 12.5);
+            assert_eq!(a, Unit::Angle(AngleUnit::Degree));
+            assert_eq!(b, Unit::Angle(AngleUnit::Gradian));
+        } else {
+            panic!("Expected ConvertTo");
+        }
+
+        let args = vec!["30'".to_string()];
+        assert!(matches!(parser(args), Task::ConvertAll(0.5, Unit::Angle(AngleUnit::Degree))));
+    }
+
+    #[test]
+    fn test_parser_wave() {
+        let args = vec!["500".to_string(), "nm".to_string(), "THz".to_string(), "wave".to_string()];
+        if let Task::ConvertWavelength(val, a, b) = parser(args) {
+            assert_eq!(val, 500.0);
+            assert_eq!(a, Unit::Length(LengthUnit::Meter(-9)));
+            assert_eq!(b, Unit::Frequency(FrequencyUnit::Hertz(12)));
+        } else {
+            panic!("Expected ConvertWavelength");
+        }
+
+        // There is no list of units to convert to
+        let args = vec!["500".to_string(), "nm".to_string(), "wave".to_string()];
+        assert!(matches!(parser(args), Task::Error(_)));
+    }
+
+    #[test]
+    fn test_parser_substance() {
+        let args = vec!["12", "g", "C6H12O6", "to", "mmol"].into_iter().map(String::from).collect();
+        if let Task::ConvertSubstance(val, a, b, formula, mass) = parser(args) {
+            assert_eq!(val, 12.0);
+            assert_eq!(a, Unit::Mass(MassUnit::Gram(0)));
+            assert_eq!(b, Unit::Amount(AmountUnit::Mole(-3)));
+            assert_eq!(formula, "C6H12O6");
+            assert_eq!(mass, Ratio::new(180_156, 1000));
+        } else {
+            panic!("Expected ConvertSubstance");
+        }
+
+        let args = vec!["1", "mol", "H2O", "g"].into_iter().map(String::from).collect();
+        assert!(matches!(parser(args), Task::ConvertSubstance(..)));
+        let args = vec!["12", "g", "Xy2", "mol"].into_iter().map(String::from).collect();
+        assert!(matches!(parser(args), Task::Error(_)));
+        let args = vec!["12", "g", "H2O", "from", "mol"].into_iter().map(String::from).collect();
+        assert!(matches!(parser(args), Task::Error(_)));
+        // Neither unit is an amount, or neither is a mass
+        let args = vec!["1", "m", "C", "ft"].into_iter().map(String::from).collect();
+        assert!(matches!(parser(args), Task::Error(_)));
+        let args = vec!["1", "mol", "H2O", "mmol"].into_iter().map(String::from).collect();
+        assert!(matches!(parser(args), Task::Error(_)));
+    }
+
+    #[test]
+    fn test_parse_options() {
+        let args = vec!["--dpi", "300", "1", "in", "px", "--font-size", "10.5"].into_iter().map(String::from).collect();
+        let (context, rest) = parse_options(args).unwrap();
+        assert_eq!(context.dpi, Ratio::from(300));
+        assert_eq!(context.font_size, Ratio::new(21, 2));
+        assert_eq!(rest, vec!["1", "in", "px"]);
+
+        let args = vec!["1", "in", "px"].into_iter().map(String::from).collect();
+        let (context, rest) = parse_options(args).unwrap();
+        assert_eq!(context, ConversionContext::default());
+        assert_eq!(rest.len(), 3);
+
+        let args = vec!["1", "in", "px", "--dpi"].into_iter().map(String::from).collect();
+        assert_eq!(parse_options(args), Err("--dpi needs a number".to_string()));
+        let args = vec!["--dpi", "high", "1", "in", "px"].into_iter().map(String::from).collect();
+        assert!(parse_options(args).is_err());
+    }
+
+    #[test]
+    fn test_parser_points() {
+        // "pt" next to a length is a point, not a pint
+        let args = vec!["12", "pt", "mm"].into_iter().map(String::from).collect();
+        if let Task::ConvertTo(_, a, b) = parser(args) {
+            assert_eq!(a, Unit::Length(LengthUnit::Point));
+            assert_eq!(b, Unit::Length(LengthUnit::Meter(-3)));
+        } else {
+            panic!("Expected ConvertTo");
+        }
+
+        let args = vec!["1", "pt", "ml"].into_iter().map(String::from).collect();
+        assert!(matches!(parser(args), Task::ConvertTo(_, Unit::Volume(_), _)));
+    }
+
+    #[test]
+    fn test_parser_compound_output() {
+        let args = vec!["5400".to_string(), "s".to_string(), "compound".to_string()];
+        if let Task::ConvertToDuration(val, unit) = parser(args) {
+            assert_eq!(val, 5400.0);
+            assert_eq!(unit, Unit::Time(TimeUnit::Second(0)));
+        } else {
+            panic!("Expected ConvertToDuration");
+        }
+
+        let args = vec!["1h 30m".to_string(), "compound".to_string()];
+        assert!(matches!(parser(args), Task::ConvertToDuration(5400.0, _)));
+
+        let args = vec!["1".to_string(), "h".to_string(), "min".to_string(), "compound".to_string()];
+        assert!(matches!(parser(args), Task::Error(_)));
+    }
+
+    #[test]
+    fn test_parser_ambiguous_symbols() {
+        // "C" and "F" are temperatures unless the other unit says otherwise
+        let args = vec!["2200".to_string(), "mAh".to_string(), "C".to_string()];
+        if let Task::ConvertTo(_, a, b) = parser(args) {
+            assert_eq!(a, Unit::Charge(ChargeUnit::AmpereHour(-3)));
+            assert_eq!(b, Unit::Charge(ChargeUnit::Coulomb(0)));
+        } else {
+            panic!("Expected ConvertTo");
+        }
+
+        let args = vec!["1".to_string(), "F".to_string(), "uF".to_string()];
+        if let Task::ConvertTo(_, a, b) = parser(args) {
+            assert_eq!(a, Unit::Capacitance(CapacitanceUnit::Farad(0)));
+            assert_eq!(b, Unit::Capacitance(CapacitanceUnit::Farad(-6)));
+        } else {
+            panic!("Expected ConvertTo");
+        }
+
+        let args = vec!["100".to_string(), "F".to_string(), "C".to_string()];
+        if let Task::ConvertTo(_, a, b) = parser(args) {
+            assert_eq!(a, Unit::Temperature(TempUnit::Fahrenheit));
+            assert_eq!(b, Unit::Temperature(TempUnit::Celsius));
+        } else {
+            panic!("Expected ConvertTo");
+        }
+
+        // So is "rad" next to a dose
+        let args = vec!["100".to_string(), "rad".to_string(), "Gy".to_string()];
+        if let Task::ConvertTo(_, a, b) = parser(args) {
+            assert_eq!(a, Unit::AbsorbedDose(AbsorbedDoseUnit::Rad(0)));
+            assert_eq!(b, Unit::AbsorbedDose(AbsorbedDoseUnit::Gray(0)));
+        } else {
+            panic!("Expected ConvertTo");
+        }
+
+        // "ps" is a picosecond; metric horsepower is "PS" next to a power
+        let args = vec!["1".to_string(), "ps".to_string(), "ns".to_string()];
+        if let Task::ConvertTo(_, a, b) = parser(args) {
+            assert_eq!(a, Unit::Time(TimeUnit::Second(-12)));
+            assert_eq!(b, Unit::Time(TimeUnit::Second(-9)));
+        } else {
+            panic!("Expected ConvertTo");
+        }
+
+        // "MA" is a megaampere, not mach
+        let args = vec!["1".to_string(), "MA".to_string(), "A".to_string()];
+        assert!(matches!(parser(args), Task::ConvertTo(_, Unit::Current(CurrentUnit::Ampere(6)), _)));
+    }
 }
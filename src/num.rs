@@ -0,0 +1,290 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::str::FromStr;
+
+/// Floating point types the conversion core can work in.
+pub trait Real:
+    Copy
+    + PartialOrd
+    + fmt::Debug
+    + fmt::Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    fn from_f64(value: f64) -> Self;
+    fn to_f64(self) -> f64;
+    fn is_finite(self) -> bool;
+}
+
+impl Real for f32 {
+    fn from_f64(value: f64) -> f32 {
+        value as f32
+    }
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    fn is_finite(self) -> bool {
+        f32::is_finite(self)
+    }
+}
+
+impl Real for f64 {
+    fn from_f64(value: f64) -> f64 {
+        value
+    }
+
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn is_finite(self) -> bool {
+        f64::is_finite(self)
+    }
+}
+
+/// An exact fraction, always kept in lowest terms with a positive
+/// denominator.
+///
+/// The arithmetic operators panic on overflow like the integer ones do;
+/// the `checked_*` methods return `None` instead.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Ratio {
+    num: i128,
+    den: i128,
+}
+
+fn gcd(mut a: i128, mut b: i128) -> i128 {
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a.abs()
+}
+
+impl Ratio {
+    pub const ZERO: Ratio = Ratio { num: 0, den: 1 };
+    pub const ONE: Ratio = Ratio { num: 1, den: 1 };
+
+    /// Panics if `den` is zero.
+    pub fn new(num: i128, den: i128) -> Ratio {
+        Ratio::checked_new(num, den).expect("Ratio denominator must not be zero")
+    }
+
+    fn checked_new(num: i128, den: i128) -> Option<Ratio> {
+        if den == 0 {
+            return None;
+        }
+        let g = gcd(num, den);
+        let (num, den) = (num / g, den / g);
+        if den < 0 {
+            Some(Ratio { num: num.checked_neg()?, den: den.checked_neg()? })
+        } else {
+            Some(Ratio { num, den })
+        }
+    }
+
+    pub fn numer(self) -> i128 {
+        self.num
+    }
+
+    pub fn denom(self) -> i128 {
+        self.den
+    }
+
+    pub fn is_zero(self) -> bool {
+        self.num == 0
+    }
+
+    pub fn to_f64(self) -> f64 {
+        self.num as f64 / self.den as f64
+    }
+
+    pub fn checked_add(self, other: Ratio) -> Option<Ratio> {
+        let g = gcd(self.den, other.den);
+        let den = (self.den / g).checked_mul(other.den)?;
+        let num = self.num
+            .checked_mul(other.den / g)?
+            .checked_add(other.num.checked_mul(self.den / g)?)?;
+        Ratio::checked_new(num, den)
+    }
+
+    pub fn checked_sub(self, other: Ratio) -> Option<Ratio> {
+        self.checked_add(Ratio { num: other.num.checked_neg()?, den: other.den })
+    }
+
+    pub fn checked_mul(self, other: Ratio) -> Option<Ratio> {
+        // Cross-reduce first so intermediate products stay small
+        let g1 = gcd(self.num, other.den);
+        let g2 = gcd(other.num, self.den);
+        let num = (self.num / g1).checked_mul(other.num / g2)?;
+        let den = (self.den / g2).checked_mul(other.den / g1)?;
+        Ratio::checked_new(num, den)
+    }
+
+    /// Returns `None` on overflow or division by zero.
+    pub fn checked_div(self, other: Ratio) -> Option<Ratio> {
+        if other.num == 0 {
+            return None;
+        }
+        self.checked_mul(Ratio { num: other.den, den: other.num })
+    }
+
+    /// Multiplies by `10^exp`.
+    pub fn checked_scale10(self, exp: i32) -> Option<Ratio> {
+        let power = 10i128.checked_pow(exp.unsigned_abs())?;
+        if exp < 0 {
+            self.checked_div(Ratio::from(power))
+        } else {
+            self.checked_mul(Ratio::from(power))
+        }
+    }
+}
+
+impl From<i128> for Ratio {
+    fn from(value: i128) -> Ratio {
+        Ratio { num: value, den: 1 }
+    }
+}
+
+impl From<i64> for Ratio {
+    fn from(value: i64) -> Ratio {
+        Ratio::from(value as i128)
+    }
+}
+
+impl From<i32> for Ratio {
+    fn from(value: i32) -> Ratio {
+        Ratio::from(value as i128)
+    }
+}
+
+impl Add for Ratio {
+    type Output = Ratio;
+
+    fn add(self, other: Ratio) -> Ratio {
+        self.checked_add(other).expect("Ratio addition overflowed")
+    }
+}
+
+impl Sub for Ratio {
+    type Output = Ratio;
+
+    fn sub(self, other: Ratio) -> Ratio {
+        self.checked_sub(other).expect("Ratio subtraction overflowed")
+    }
+}
+
+impl Mul for Ratio {
+    type Output = Ratio;
+
+    fn mul(self, other: Ratio) -> Ratio {
+        self.checked_mul(other).expect("Ratio multiplication overflowed")
+    }
+}
+
+impl Div for Ratio {
+    type Output = Ratio;
+
+    fn div(self, other: Ratio) -> Ratio {
+        self.checked_div(other).expect("Ratio division overflowed or divided by zero")
+    }
+}
+
+impl Neg for Ratio {
+    type Output = Ratio;
+
+    fn neg(self) -> Ratio {
+        Ratio { num: -self.num, den: self.den }
+    }
+}
+
+impl PartialOrd for Ratio {
+    fn partial_cmp(&self, other: &Ratio) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Ratio {
+    fn cmp(&self, other: &Ratio) -> Ordering {
+        // Denominators are positive, so cross-multiplying keeps the order.
+        // Fall back to floats only if that overflows.
+        match (self.num.checked_mul(other.den), other.num.checked_mul(self.den)) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            _ => self.to_f64().total_cmp(&other.to_f64()),
+        }
+    }
+}
+
+/// Prints `3/4`, or just `3` for whole numbers.
+impl fmt::Display for Ratio {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.den == 1 {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ParseRatioError {
+    token: String,
+}
+
+impl fmt::Display for ParseRatioError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Invalid number '{}'", self.token)
+    }
+}
+
+impl std::error::Error for ParseRatioError {}
+
+fn parse_decimal(s: &str) -> Option<Ratio> {
+    let (mantissa, exp) = match s.split_once(['e', 'E']) {
+        Some((mantissa, exp)) => (mantissa, exp.parse::<i32>().ok()?),
+        None => (s, 0),
+    };
+    let (negative, mantissa) = match mantissa.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, mantissa.strip_prefix('+').unwrap_or(mantissa)),
+    };
+    let (int, frac) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    if int.is_empty() && frac.is_empty() {
+        return None;
+    }
+    if !int.chars().chain(frac.chars()).all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let digits = format!("{int}{frac}");
+    let mut num = digits.parse::<i128>().ok()?;
+    if negative {
+        num = -num;
+    }
+    let frac_len = i32::try_from(frac.len()).ok()?;
+    Ratio::from(num).checked_scale10(exp.checked_sub(frac_len)?)
+}
+
+/// Accepts decimals such as `-1.25` or `2.5e-3` and fractions such as `3/8`,
+/// all of which are represented exactly.
+impl FromStr for Ratio {
+    type Err = ParseRatioError;
+
+    fn from_str(s: &str) -> Result<Ratio, ParseRatioError> {
+        let s = s.trim();
+        let parsed = match s.split_once('/') {
+            Some((num, den)) => parse_decimal(num.trim())
+                .zip(parse_decimal(den.trim()))
+                .and_then(|(num, den)| num.checked_div(den)),
+            None => parse_decimal(s),
+        };
+        parsed.ok_or_else(|| ParseRatioError { token: s.to_string() })
+    }
+}
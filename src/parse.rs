@@ -0,0 +1,733 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{binary_scale_to_string, binary_scale_to_symbol, BINARY_PREFIX_SCALES};
+use crate::{scale_to_string, scale_to_symbol, PREFIX_SCALES};
+use crate::{AngleUnit, AreaUnit, ConcentrationUnit, DataRateUnit, DataUnit, EnergyUnit, ForceUnit, FrequencyUnit, FuelEconomyUnit, LengthUnit, MassUnit, PowerUnit, PressureUnit, SpeedUnit, TempUnit, TimeUnit};
+use crate::{DensityUnit, Dimension, FlowRateUnit, MassFlowUnit, SolidAngleUnit, TorqueUnit, Unit, VolUnit};
+use crate::{customary_volume, VolumeSystem};
+use crate::{CapacitanceUnit, ChargeUnit, ConductanceUnit, CurrentUnit, InductanceUnit, MagneticFluxDensityUnit};
+use crate::{MagneticFluxUnit, ResistanceUnit, VoltageUnit};
+use crate::{IlluminanceUnit, LuminanceUnit, LuminousFluxUnit, LuminousIntensityUnit};
+use crate::{AbsorbedDoseUnit, EquivalentDoseUnit, RadioactivityUnit};
+use crate::{AccelerationUnit, AmountUnit};
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ParseUnitError {
+    token: String,
+}
+
+impl ParseUnitError {
+    pub(crate) fn new(token: &str) -> ParseUnitError {
+        ParseUnitError { token: token.to_string() }
+    }
+
+    /// The text that could not be recognised as a unit.
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+}
+
+impl fmt::Display for ParseUnitError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Unknown unit '{}'", self.token)
+    }
+}
+
+impl std::error::Error for ParseUnitError {}
+
+/// Accepts symbols ("km"), long names ("kilometers") and the common
+/// aliases listed by the CLI.
+///
+/// Names and aliases ignore case, but prefixed symbols are read in their
+/// SI case only: "Mg" is a megagram and "mg" a milligram, while "MG" is an
+/// error. "PA" is a petaampere, not a pascal, and "MS" a megasiemens. A
+/// unit symbol may be written in lower case, as in "kwh", only where the
+/// prefix cannot be read in another case, so "mhz" and "mn" are errors
+/// rather than millihertz and millinewtons.
+impl FromStr for Unit {
+    type Err = ParseUnitError;
+
+    fn from_str(s: &str) -> Result<Unit, ParseUnitError> {
+        let trimmed = s.trim();
+        let token = trimmed.to_lowercase();
+        parse_exact(trimmed)
+            .or_else(|| parse_alias(&token))
+            .or_else(|| parse_system(&token))
+            .or_else(|| parse_metric_symbol(trimmed))
+            .or_else(|| parse_binary_symbol(trimmed))
+            .or_else(|| parse_metric_name(&token))
+            .or_else(|| parse_binary_name(&token))
+            .or_else(|| parse_per(trimmed))
+            .ok_or_else(|| ParseUnitError::new(s))
+    }
+}
+
+impl Unit {
+    /// Parses `s` like [`str::parse`], but reads a symbol with several
+    /// meanings as a unit of `dimension` where it has one. "F" is degrees
+    /// Fahrenheit on its own and a farad when a capacitance is expected.
+    pub fn parse_as(s: &str, dimension: Dimension) -> Result<Unit, ParseUnitError> {
+        let unit = s.parse::<Unit>()?;
+        if unit.dimension() == dimension {
+            return Ok(unit);
+        }
+        Ok(parse_alternative(s.trim(), dimension).unwrap_or(unit))
+    }
+}
+
+/// Other readings of symbols that [`Unit`]'s `FromStr` already gives a
+/// meaning to.
+fn parse_alternative(token: &str, dimension: Dimension) -> Option<Unit> {
+    let unit = match token {
+        "C" => Unit::Charge(ChargeUnit::Coulomb(0)),
+        "F" => Unit::Capacitance(CapacitanceUnit::Farad(0)),
+        // Metric horsepower; on its own, "PS" is the petasiemens and "ps"
+        // the picosecond
+        "PS" => Unit::Power(PowerUnit::HorsepowerMetric),
+        "pt" => Unit::Length(LengthUnit::Point),
+        // The rad of absorbed dose, with any prefix of the radian's
+        _ => match token.parse() {
+            Ok(Unit::Angle(AngleUnit::Radian(scale))) if token.ends_with("rad") => {
+                Unit::AbsorbedDose(AbsorbedDoseUnit::Rad(scale))
+            }
+            _ => return None,
+        },
+    };
+    (unit.dimension() == dimension).then_some(unit)
+}
+
+/// Symbols that only differ from another unit by case.
+fn parse_exact(token: &str) -> Option<Unit> {
+    match token {
+        "Cal" => Some(Unit::Energy(EnergyUnit::FoodCalorie)),
+        // Common spelling of kB, which would otherwise read as kilobits
+        "KB" => Some(Unit::Data(DataUnit::Byte(3))),
+        // Kilonewtons, not knots
+        "kN" => Some(Unit::Force(ForceUnit::Newton(3))),
+        // Electrical units rather than years, mach, hours, grams, pints and feet
+        "A" => Some(Unit::Current(CurrentUnit::Ampere(0))),
+        "mA" => Some(Unit::Current(CurrentUnit::Ampere(-3))),
+        // Megaamperes, hectoamperes and petahenries rather than mach,
+        // hectares and phots
+        "MA" => Some(Unit::Current(CurrentUnit::Ampere(6))),
+        "hA" => Some(Unit::Current(CurrentUnit::Ampere(2))),
+        "PH" => Some(Unit::Inductance(InductanceUnit::Henry(15))),
+        "H" => Some(Unit::Inductance(InductanceUnit::Henry(0))),
+        "G" => Some(Unit::MagneticFluxDensity(MagneticFluxDensityUnit::Gauss)),
+        // Exameters, not ems
+        "Em" => Some(Unit::Length(LengthUnit::Meter(18))),
+        // Teslas, not tonnes
+        "T" => Some(Unit::MagneticFluxDensity(MagneticFluxDensityUnit::Tesla(0))),
+        "pT" => Some(Unit::MagneticFluxDensity(MagneticFluxDensityUnit::Tesla(-12))),
+        "fT" => Some(Unit::MagneticFluxDensity(MagneticFluxDensityUnit::Tesla(-15))),
+        // Picocoulombs and centihenries rather than parsecs and chains
+        "pC" => Some(Unit::Charge(ChargeUnit::Coulomb(-12))),
+        "cH" => Some(Unit::Inductance(InductanceUnit::Henry(-2))),
+        // Picohenries rather than phots
+        "pH" => Some(Unit::Inductance(InductanceUnit::Henry(-12))),
+        // Centiteslas, not carats
+        "cT" => Some(Unit::MagneticFluxDensity(MagneticFluxDensityUnit::Tesla(-2))),
+        "Rd" => Some(Unit::Radioactivity(RadioactivityUnit::Rutherford)),
+        // How a coulomb and a farad are printed, as "C" and "F" are
+        // temperatures
+        "C_charge" => Some(Unit::Charge(ChargeUnit::Coulomb(0))),
+        "F_capacitance" => Some(Unit::Capacitance(CapacitanceUnit::Farad(0))),
+        // The gal of acceleration rather than the gallon
+        "Gal" => Some(Unit::Acceleration(AccelerationUnit::Gal(0))),
+        _ => None,
+    }
+}
+
+fn parse_alias(token: &str) -> Option<Unit> {
+    match token {
+        // Temperature
+        "k" | "kelvin" => Some(Unit::Temperature(TempUnit::Kelvin)),
+        "c" | "celsius" => Some(Unit::Temperature(TempUnit::Celsius)),
+        "f" | "fahrenheit" => Some(Unit::Temperature(TempUnit::Fahrenheit)),
+
+        // Length
+        "in" | "inch" | "inches" => Some(Unit::Length(LengthUnit::Inch)),
+        "ft" | "foot" | "feet" => Some(Unit::Length(LengthUnit::Feet)),
+        "yd" | "yard" | "yards" => Some(Unit::Length(LengthUnit::Yard)),
+        "mi" | "mile" | "miles" => Some(Unit::Length(LengthUnit::Mile)),
+        "mil" | "mils" | "thou" | "thous" => Some(Unit::Length(LengthUnit::Mil)),
+        "micron" | "microns" => Some(Unit::Length(LengthUnit::Meter(-6))),
+        "nmi" | "nautical mile" | "nautical miles" => Some(Unit::Length(LengthUnit::NauticalMile)),
+        "ftm" | "fathom" | "fathoms" => Some(Unit::Length(LengthUnit::Fathom)),
+        "ch" | "chain" | "chains" => Some(Unit::Length(LengthUnit::Chain)),
+        "li" | "lnk" | "link" | "links" => Some(Unit::Length(LengthUnit::Link)),
+        // "Rd" is the rutherford
+        "rd" | "rod" | "rods" | "perch" | "perches" | "pole" | "poles" => Some(Unit::Length(LengthUnit::Rod)),
+        "fur" | "furlong" | "furlongs" => Some(Unit::Length(LengthUnit::Furlong)),
+        "lea" | "league" | "leagues" => Some(Unit::Length(LengthUnit::League)),
+        "ft_us" | "survey ft" | "survey foot" | "survey feet" | "us survey foot" | "us survey feet" => {
+            Some(Unit::Length(LengthUnit::SurveyFoot))
+        }
+        "mi_us" | "survey mi" | "survey mile" | "survey miles" | "us survey mile" | "us survey miles" => {
+            Some(Unit::Length(LengthUnit::SurveyMile))
+        }
+        // Also the angstrom sign, which lowercases to the same letter
+        "å" | "angstrom" | "angstroms" | "ångström" | "ångströms" => Some(Unit::Length(LengthUnit::Angstrom)),
+        "au" | "astronomical unit" | "astronomical units" => Some(Unit::Length(LengthUnit::AstronomicalUnit)),
+        "ly" | "light-year" | "light-years" | "light year" | "light years" => Some(Unit::Length(LengthUnit::LightYear)),
+        "pc" | "parsec" | "parsecs" => Some(Unit::Length(LengthUnit::Parsec)),
+        // "pt" is a pint, unless a length is expected
+        "pt_dtp" | "point" | "points" => Some(Unit::Length(LengthUnit::Point)),
+        "pica" | "picas" => Some(Unit::Length(LengthUnit::Pica)),
+        "dd" | "didot" | "didot point" | "didot points" => Some(Unit::Length(LengthUnit::DidotPoint)),
+        "cicero" | "ciceros" => Some(Unit::Length(LengthUnit::Cicero)),
+        "twip" | "twips" => Some(Unit::Length(LengthUnit::Twip)),
+        // "Em" is the exameter
+        "em" | "ems" => Some(Unit::Length(LengthUnit::Em)),
+        "px" | "pixel" | "pixels" => Some(Unit::Length(LengthUnit::Pixel)),
+
+        // Area
+        "in2" | "square inch" | "square inches" => Some(Unit::Area(AreaUnit::Inch2)),
+        "ft2" | "sqft" | "square foot" | "square feet" => Some(Unit::Area(AreaUnit::Feet2)),
+        "yd2" | "square yard" | "square yards" => Some(Unit::Area(AreaUnit::Yard2)),
+        "mi2" | "square mile" | "square miles" => Some(Unit::Area(AreaUnit::Mile2)),
+        "ac" | "acre" | "acres" => Some(Unit::Area(AreaUnit::Acre)),
+        "ha" | "hectare" | "hectares" => Some(Unit::Area(AreaUnit::Hectare)),
+
+        // Volume
+        "tsp" | "teaspoon" | "teaspoons" => Some(Unit::Volume(VolUnit::TeaSpoon(VolumeSystem::Metric))),
+        "tbsp" | "tablespoon" | "tablespoons" => Some(Unit::Volume(VolUnit::TableSpoon(VolumeSystem::Metric))),
+        "fl oz" | "floz" | "fl. oz." | "fluid ounce" | "fluid ounces" => {
+            Some(Unit::Volume(VolUnit::FluidOunce(VolumeSystem::UsLiquid)))
+        }
+        "gi" | "gill" | "gills" => Some(Unit::Volume(VolUnit::Gill(VolumeSystem::UsLiquid))),
+        "cup" | "cups" => Some(Unit::Volume(VolUnit::Cup(VolumeSystem::UsLiquid))),
+        "pt" | "pint" | "pints" => Some(Unit::Volume(VolUnit::Pint(VolumeSystem::UsLiquid))),
+        "qt" | "quart" | "quarts" => Some(Unit::Volume(VolUnit::Quart(VolumeSystem::UsLiquid))),
+        "gal" | "gallon" | "gallons" => Some(Unit::Volume(VolUnit::Gallon(VolumeSystem::UsLiquid))),
+        "pk" | "peck" | "pecks" => Some(Unit::Volume(VolUnit::Peck(VolumeSystem::UsDry))),
+        "bu" | "bushel" | "bushels" => Some(Unit::Volume(VolUnit::Bushel(VolumeSystem::UsDry))),
+        "in3" | "in³" | "cu in" | "cubic inch" | "cubic inches" => Some(Unit::Volume(VolUnit::Inch3)),
+        "ft3" | "ft³" | "cu ft" | "cuft" | "cubic foot" | "cubic feet" => Some(Unit::Volume(VolUnit::Feet3)),
+        "yd3" | "yd³" | "cu yd" | "cubic yard" | "cubic yards" => Some(Unit::Volume(VolUnit::Yard3)),
+        "bbl" | "barrel" | "barrels" => Some(Unit::Volume(VolUnit::Barrel)),
+
+        // Mass
+        "oz" | "ounce" | "ounces" => Some(Unit::Mass(MassUnit::Ounce)),
+        "lb" | "pound" | "pounds" => Some(Unit::Mass(MassUnit::Pound)),
+        "st" | "stone" | "stones" => Some(Unit::Mass(MassUnit::Stone)),
+        "cwt" | "hundredweight" | "hundredweights" | "short hundredweight" | "short hundredweights" => {
+            Some(Unit::Mass(MassUnit::ShortHundredweight))
+        }
+        "long hundredweight" | "long hundredweights" => Some(Unit::Mass(MassUnit::LongHundredweight)),
+        "ton" | "tons" | "short ton" | "short tons" => Some(Unit::Mass(MassUnit::ShortTon)),
+        "long ton" | "long tons" => Some(Unit::Mass(MassUnit::LongTon)),
+        "t" | "tonne" | "tonnes" | "metric ton" | "metric tons" => Some(Unit::Mass(MassUnit::Tonne)),
+        "dr" | "dram" | "drams" | "drachm" | "drachms" => Some(Unit::Mass(MassUnit::Dram)),
+        "gr" | "grain" | "grains" => Some(Unit::Mass(MassUnit::Grain)),
+        // Troy and apothecaries' units, which "oz", "lb" and "dr" alone never
+        // mean
+        "ozt" | "oz t" | "troy oz" | "troy ounce" | "troy ounces" => Some(Unit::Mass(MassUnit::TroyOunce)),
+        "lbt" | "lb t" | "troy lb" | "troy pound" | "troy pounds" => Some(Unit::Mass(MassUnit::TroyPound)),
+        "dwt" | "pennyweight" | "pennyweights" => Some(Unit::Mass(MassUnit::Pennyweight)),
+        "dr_ap" | "dr ap" | "ʒ" | "apothecaries' dram" | "apothecaries' drams" | "apothecary dram"
+        | "apothecary drams" => Some(Unit::Mass(MassUnit::ApothecaryDram)),
+        "s_ap" | "s ap" | "℈" | "scruple" | "scruples" => Some(Unit::Mass(MassUnit::Scruple)),
+        "ct" | "carat" | "carats" => Some(Unit::Mass(MassUnit::Carat)),
+        "slug" | "slugs" => Some(Unit::Mass(MassUnit::Slug)),
+
+        // Speed
+        "km/h" | "kmh" | "kph" | "kilometer per hour" | "kilometers per hour" => {
+            Some(Unit::Speed(SpeedUnit::KilometerPerHour))
+        }
+        "mph" | "mi/h" | "mile per hour" | "miles per hour" => Some(Unit::Speed(SpeedUnit::MilePerHour)),
+        "ft/s" | "fps" | "foot per second" | "feet per second" => Some(Unit::Speed(SpeedUnit::FootPerSecond)),
+        "kn" | "kt" | "knot" | "knots" => Some(Unit::Speed(SpeedUnit::Knot)),
+        "ma" | "mach" => Some(Unit::Speed(SpeedUnit::Mach)),
+        "c0" | "speed of light" => Some(Unit::Speed(SpeedUnit::SpeedOfLight)),
+
+        // Time
+        "sec" | "secs" => Some(Unit::Time(TimeUnit::Second(0))),
+        "min" | "mins" | "minute" | "minutes" => Some(Unit::Time(TimeUnit::Minute)),
+        "h" | "hr" | "hrs" | "hour" | "hours" => Some(Unit::Time(TimeUnit::Hour)),
+        "d" | "day" | "days" => Some(Unit::Time(TimeUnit::Day)),
+        "wk" | "week" | "weeks" => Some(Unit::Time(TimeUnit::Week)),
+        "yr" | "a" | "year" | "years" | "julian year" | "julian years" => {
+            Some(Unit::Time(TimeUnit::JulianYear))
+        }
+
+        // Pressure
+        "atm" | "atmosphere" | "atmospheres" => Some(Unit::Pressure(PressureUnit::Atmosphere)),
+        "psi" | "lbf/in2" | "pound per square inch" | "pounds per square inch" => {
+            Some(Unit::Pressure(PressureUnit::Psi))
+        }
+        "psig" | "pound per square inch gauge" | "pounds per square inch gauge" => {
+            Some(Unit::Pressure(PressureUnit::Psig))
+        }
+        "mmhg" | "millimeter of mercury" | "millimeters of mercury" => {
+            Some(Unit::Pressure(PressureUnit::MillimeterOfMercury))
+        }
+        "inhg" | "inch of mercury" | "inches of mercury" => Some(Unit::Pressure(PressureUnit::InchOfMercury)),
+        "torr" => Some(Unit::Pressure(PressureUnit::Torr)),
+        "kgf/cm2" | "kgf/cm²" | "at" | "kilogram-force per square centimeter"
+        | "kilograms-force per square centimeter" => Some(Unit::Pressure(PressureUnit::KilogramForcePerCm2)),
+
+        // Energy
+        "cal_it" | "it calorie" | "it calories" => Some(Unit::Energy(EnergyUnit::CalorieIt)),
+        "kcal_it" | "it kilocalorie" | "it kilocalories" => Some(Unit::Energy(EnergyUnit::KilocalorieIt)),
+        "food calorie" | "food calories" => Some(Unit::Energy(EnergyUnit::FoodCalorie)),
+        "btu" | "btu_it" | "british thermal unit" | "british thermal units" => {
+            Some(Unit::Energy(EnergyUnit::BtuIt))
+        }
+        "btu_th" | "thermochemical british thermal unit" | "thermochemical british thermal units" => {
+            Some(Unit::Energy(EnergyUnit::BtuThermochemical))
+        }
+        "erg" | "ergs" => Some(Unit::Energy(EnergyUnit::Erg)),
+        "thm" | "therm" | "therms" => Some(Unit::Energy(EnergyUnit::Therm)),
+        "ft·lbf" | "ft-lbf" | "ft*lbf" | "ftlbf" | "foot-pound" | "foot-pounds" => {
+            Some(Unit::Energy(EnergyUnit::FootPound))
+        }
+
+        // Power
+        "hp" | "horsepower" | "mechanical horsepower" => Some(Unit::Power(PowerUnit::HorsepowerMechanical)),
+        "hp(m)" | "metric horsepower" => Some(Unit::Power(PowerUnit::HorsepowerMetric)),
+        "hp(e)" | "electrical horsepower" => Some(Unit::Power(PowerUnit::HorsepowerElectrical)),
+        "hp(s)" | "boiler horsepower" => Some(Unit::Power(PowerUnit::HorsepowerBoiler)),
+        "btu/h" | "btu/hr" | "btuh" | "british thermal unit per hour" | "british thermal units per hour" => {
+            Some(Unit::Power(PowerUnit::BtuPerHour))
+        }
+        "tr" | "ton of refrigeration" | "tons of refrigeration" => Some(Unit::Power(PowerUnit::TonOfRefrigeration)),
+        "erg/s" | "erg per second" | "ergs per second" => Some(Unit::Power(PowerUnit::ErgPerSecond)),
+        "dbm" | "decibel-milliwatt" | "decibel-milliwatts" => Some(Unit::Power(PowerUnit::DecibelMilliwatt)),
+        "dbw" | "decibel-watt" | "decibel-watts" => Some(Unit::Power(PowerUnit::DecibelWatt)),
+
+        // Data
+        "nibble" | "nibbles" | "nybble" | "nybbles" => Some(Unit::Data(DataUnit::Nibble)),
+
+        // Angle
+        "deg" | "°" | "degree" | "degrees" => Some(Unit::Angle(AngleUnit::Degree)),
+        "arcmin" | "′" | "arcminute" | "arcminutes" => Some(Unit::Angle(AngleUnit::Arcminute)),
+        "arcsec" | "″" | "arcsecond" | "arcseconds" => Some(Unit::Angle(AngleUnit::Arcsecond)),
+        "gon" | "grad" | "gradian" | "gradians" => Some(Unit::Angle(AngleUnit::Gradian)),
+        "turn" | "turns" | "rev" | "revolution" | "revolutions" => Some(Unit::Angle(AngleUnit::Turn)),
+        "mil_nato" | "nato mil" | "nato mils" => Some(Unit::Angle(AngleUnit::MilNato)),
+        "mil_wp" | "warsaw pact mil" | "warsaw pact mils" => Some(Unit::Angle(AngleUnit::MilWarsawPact)),
+        "mil_se" | "streck" | "swedish mil" | "swedish mils" => Some(Unit::Angle(AngleUnit::MilSwedish)),
+
+        // Solid angle
+        "deg2" | "deg²" | "sq deg" | "square degree" | "square degrees" => {
+            Some(Unit::SolidAngle(SolidAngleUnit::SquareDegree))
+        }
+
+        // Force
+        "dyn" | "dyne" | "dynes" => Some(Unit::Force(ForceUnit::Dyne)),
+        "kgf" | "kp" | "kilopond" | "kiloponds" | "kilogram-force" | "kilograms-force" => {
+            Some(Unit::Force(ForceUnit::KilogramForce))
+        }
+        "lbf" | "pound-force" | "pounds-force" => Some(Unit::Force(ForceUnit::PoundForce)),
+        "pdl" | "poundal" | "poundals" => Some(Unit::Force(ForceUnit::Poundal)),
+        "kip" | "kips" | "kipf" => Some(Unit::Force(ForceUnit::Kip)),
+
+        // Torque. Note "ft·lbf" is the foot-pound of energy.
+        "lbf·ft" | "lbf-ft" | "lbf*ft" | "lbfft" | "lb-ft" | "pound-force foot" | "pound-force feet" => {
+            Some(Unit::Torque(TorqueUnit::PoundForceFoot))
+        }
+        "lbf·in" | "lbf-in" | "lbf*in" | "lbfin" | "lb-in" | "pound-force inch" | "pound-force inches" => {
+            Some(Unit::Torque(TorqueUnit::PoundForceInch))
+        }
+        "kgf·m" | "kgf-m" | "kgf*m" | "kgfm" | "kilogram-force meter" | "kilogram-force meters" => {
+            Some(Unit::Torque(TorqueUnit::KilogramForceMeter))
+        }
+
+        // Frequency
+        "rpm" | "r/min" | "rev/min" | "revolution per minute" | "revolutions per minute" => {
+            Some(Unit::Frequency(FrequencyUnit::RevolutionPerMinute))
+        }
+        "rad/s" | "radian per second" | "radians per second" => Some(Unit::Frequency(FrequencyUnit::RadianPerSecond)),
+        "cpm" | "cycle per minute" | "cycles per minute" => Some(Unit::Frequency(FrequencyUnit::CyclePerMinute)),
+        "bpm" | "beat per minute" | "beats per minute" => Some(Unit::Frequency(FrequencyUnit::BeatPerMinute)),
+
+        // Fuel economy
+        "l/100km" | "l/100 km" | "liters per 100 kilometers" | "litres per 100 kilometres" => {
+            Some(Unit::FuelEconomy(FuelEconomyUnit::LitersPer100Km))
+        }
+        "km/l" | "kmpl" | "kilometers per liter" | "kilometres per litre" => {
+            Some(Unit::FuelEconomy(FuelEconomyUnit::KilometersPerLiter))
+        }
+        "mpg" | "mpg_us" | "us mpg" | "miles per gallon" | "miles per us gallon" => {
+            Some(Unit::FuelEconomy(FuelEconomyUnit::MilesPerGallonUs))
+        }
+        "mpg_uk" | "uk mpg" | "mpg_imp" | "miles per imperial gallon" => {
+            Some(Unit::FuelEconomy(FuelEconomyUnit::MilesPerGallonUk))
+        }
+
+        // Density
+        "sg" | "specific gravity" => Some(Unit::Density(DensityUnit::SpecificGravity)),
+
+        // Electrical
+        "amp" | "amps" => Some(Unit::Current(CurrentUnit::Ampere(0))),
+        "gauss" => Some(Unit::MagneticFluxDensity(MagneticFluxDensityUnit::Gauss)),
+
+        // Photometry
+        "fc" | "ftc" | "foot-candle" | "foot-candles" | "footcandle" | "footcandles" => {
+            Some(Unit::Illuminance(IlluminanceUnit::FootCandle))
+        }
+        "ph" | "phot" | "phots" => Some(Unit::Illuminance(IlluminanceUnit::Phot)),
+        "nit" | "nits" | "nt" | "cd/m2" | "cd/m²" | "candela per square meter" | "candelas per square meter" => {
+            Some(Unit::Luminance(LuminanceUnit::Nit))
+        }
+        // Not "fL", which is the femtoliter
+        "ftl" | "ft-l" | "ft·l" | "foot-lambert" | "foot-lamberts" | "footlambert" | "footlamberts" => {
+            Some(Unit::Luminance(LuminanceUnit::FootLambert))
+        }
+
+        // Radioactivity
+        "rutherford" | "rutherfords" => Some(Unit::Radioactivity(RadioactivityUnit::Rutherford)),
+        "dpm" | "disintegration per minute" | "disintegrations per minute" => {
+            Some(Unit::Radioactivity(RadioactivityUnit::DisintegrationPerMinute))
+        }
+
+        // Acceleration
+        "ft/s2" | "ft/s²" | "ft/s^2" | "foot per second squared" | "feet per second squared" => {
+            Some(Unit::Acceleration(AccelerationUnit::FootPerSecond2))
+        }
+        "g0" | "g₀" | "gn" | "g-force" | "standard gravity" | "standard gravities" => {
+            Some(Unit::Acceleration(AccelerationUnit::StandardGravity))
+        }
+
+        // Amount of substance
+        "particle" | "particles" | "molecule" | "molecules" | "atom" | "atoms" => {
+            Some(Unit::Amount(AmountUnit::Particle))
+        }
+
+        // Flow rate
+        "gpm" => Some(Unit::FlowRate(FlowRateUnit::VolumePerTime(
+            VolUnit::Gallon(VolumeSystem::UsLiquid),
+            TimeUnit::Minute,
+        ))),
+        "cfm" => Some(Unit::FlowRate(FlowRateUnit::VolumePerTime(VolUnit::Feet3, TimeUnit::Minute))),
+        "bpd" => Some(Unit::FlowRate(FlowRateUnit::VolumePerTime(VolUnit::Barrel, TimeUnit::Day))),
+
+        // Concentration
+        "ppm" | "parts per million" => Some(Unit::Concentration(ConcentrationUnit::PartsPerMillion)),
+        "ppb" | "parts per billion" => Some(Unit::Concentration(ConcentrationUnit::PartsPerBillion)),
+        "%w/v" | "% w/v" | "w/v" | "percent w/v" | "percent weight per volume" => {
+            Some(Unit::Concentration(ConcentrationUnit::PercentWeightPerVolume))
+        }
+
+        _ => None,
+    }
+}
+
+/// Whether the symbol of the prefix `scale` is another prefix's in the
+/// other case, as milli and mega are.
+fn has_case_twin(scale: i8) -> bool {
+    let Some(symbol) = scale_to_symbol(scale) else {
+        return false;
+    };
+    let twin: String = symbol
+        .chars()
+        .map(|c| if c.is_uppercase() { c.to_ascii_lowercase() } else { c.to_ascii_uppercase() })
+        .collect();
+    PREFIX_SCALES.iter().any(|&s| s != scale && scale_to_symbol(s) == Some(twin.as_str()))
+}
+
+/// Prefixed metric symbols such as "mm", "km2", "mL" or "µg".
+fn parse_metric_symbol(token: &str) -> Option<Unit> {
+    // Micro is also written with an ASCII "u" or a Greek mu
+    let micro;
+    let token = match token.strip_prefix(['u', 'μ']) {
+        Some(rest) => {
+            micro = format!("µ{rest}");
+            micro.as_str()
+        }
+        None => token,
+    };
+
+    for scale in PREFIX_SCALES {
+        let Some(base) = scale_to_symbol(scale).and_then(|p| token.strip_prefix(p)) else {
+            continue;
+        };
+        let unit = match base {
+            "m" => Unit::Length(LengthUnit::Meter(scale)),
+            "m2" => Unit::Area(AreaUnit::Meter2(scale)),
+            "m3" => Unit::Volume(VolUnit::Meter3(scale)),
+            "L" => Unit::Volume(VolUnit::Liter(scale)),
+            // Only with everyday prefixes, so that "fl" is not a femtoliter
+            "l" if (-6..=3).contains(&scale) => Unit::Volume(VolUnit::Liter(scale)),
+            "g" => Unit::Mass(MassUnit::Gram(scale)),
+            "m/s" => Unit::Speed(SpeedUnit::MeterPerSecond(scale)),
+            "s" => Unit::Time(TimeUnit::Second(scale)),
+            "Pa" | "pa" => Unit::Pressure(PressureUnit::Pascal(scale)),
+            "bar" => Unit::Pressure(PressureUnit::Bar(scale)),
+            "J" | "j" => Unit::Energy(EnergyUnit::Joule(scale)),
+            "Wh" | "wh" => Unit::Energy(EnergyUnit::WattHour(scale)),
+            "cal" => Unit::Energy(EnergyUnit::Calorie(scale)),
+            "eV" | "ev" => Unit::Energy(EnergyUnit::Electronvolt(scale)),
+            "W" | "w" => Unit::Power(PowerUnit::Watt(scale)),
+            // Fractions of a bit make no sense, so "mb" is not a millibit
+            "b" | "bit" if scale >= 0 => Unit::Data(DataUnit::Bit(scale)),
+            "B" if scale >= 0 => Unit::Data(DataUnit::Byte(scale)),
+            "bit/s" | "b/s" | "bps" if scale >= 0 => Unit::DataRate(DataRateUnit::BitPerSecond(scale)),
+            "B/s" | "Bps" if scale >= 0 => Unit::DataRate(DataRateUnit::BytePerSecond(scale)),
+            "rad" => Unit::Angle(AngleUnit::Radian(scale)),
+            "rad_dose" => Unit::AbsorbedDose(AbsorbedDoseUnit::Rad(scale)),
+            "sr" => Unit::SolidAngle(SolidAngleUnit::Steradian(scale)),
+            "N" | "n" => Unit::Force(ForceUnit::Newton(scale)),
+            "Hz" | "hz" => Unit::Frequency(FrequencyUnit::Hertz(scale)),
+            "mol/L" | "mol/l" => Unit::Concentration(ConcentrationUnit::MolePerLiter(scale)),
+            // Upper case only: "c", "f" and "h" are taken, and "s" is the second
+            "C" => Unit::Charge(ChargeUnit::Coulomb(scale)),
+            "Ah" | "ah" => Unit::Charge(ChargeUnit::AmpereHour(scale)),
+            "A" => Unit::Current(CurrentUnit::Ampere(scale)),
+            "V" | "v" => Unit::Voltage(VoltageUnit::Volt(scale)),
+            "Ω" | "Ω" | "ohm" => Unit::Resistance(ResistanceUnit::Ohm(scale)),
+            "S" => Unit::Conductance(ConductanceUnit::Siemens(scale)),
+            "F" => Unit::Capacitance(CapacitanceUnit::Farad(scale)),
+            "H" => Unit::Inductance(InductanceUnit::Henry(scale)),
+            "T" => Unit::MagneticFluxDensity(MagneticFluxDensityUnit::Tesla(scale)),
+            "Wb" | "wb" => Unit::MagneticFlux(MagneticFluxUnit::Weber(scale)),
+            "cd" => Unit::LuminousIntensity(LuminousIntensityUnit::Candela(scale)),
+            "lm" => Unit::LuminousFlux(LuminousFluxUnit::Lumen(scale)),
+            "lx" => Unit::Illuminance(IlluminanceUnit::Lux(scale)),
+            "Bq" | "bq" => Unit::Radioactivity(RadioactivityUnit::Becquerel(scale)),
+            "Ci" | "ci" => Unit::Radioactivity(RadioactivityUnit::Curie(scale)),
+            "Gy" | "gy" => Unit::AbsorbedDose(AbsorbedDoseUnit::Gray(scale)),
+            "Sv" | "sv" => Unit::EquivalentDose(EquivalentDoseUnit::Sievert(scale)),
+            "rem" => Unit::EquivalentDose(EquivalentDoseUnit::Rem(scale)),
+            "m/s2" | "m/s²" | "m/s^2" => Unit::Acceleration(AccelerationUnit::MeterPerSecond2(scale)),
+            "Gal" => Unit::Acceleration(AccelerationUnit::Gal(scale)),
+            "mol" => Unit::Amount(AmountUnit::Mole(scale)),
+            // Not "nm", which is the nanometer
+            "N·m" | "N*m" | "N-m" | "Nm" | "n·m" | "n*m" | "n-m" => {
+                Unit::Torque(TorqueUnit::NewtonMeter(scale))
+            }
+            _ => continue,
+        };
+
+        // A unit symbol in the wrong case, as in "mhz" or "mn", leaves the
+        // case of the prefix in doubt too: milli or mega? The liter is "l"
+        // as well as "L", so that case is no mistake.
+        let symbol = format!("{unit:#}").replace('L', "l");
+        let canonical = scale_to_symbol(scale).and_then(|p| symbol.strip_prefix(p)).unwrap_or(&symbol);
+        let base = base.replace('L', "l");
+        if base != canonical && base.to_lowercase() == canonical.to_lowercase() && has_case_twin(scale) {
+            continue;
+        }
+        return Some(unit);
+    }
+
+    None
+}
+
+/// Qualifiers naming the system of a customary unit, before it ("uk gal",
+/// "imperial gallons") or after it ("gal_uk").
+const SYSTEM_PREFIXES: [(&str, VolumeSystem); 11] = [
+    ("us liquid ", VolumeSystem::UsLiquid),
+    ("us dry ", VolumeSystem::UsDry),
+    ("us ", VolumeSystem::UsLiquid),
+    ("dry ", VolumeSystem::UsDry),
+    ("uk ", VolumeSystem::Imperial),
+    ("imp ", VolumeSystem::Imperial),
+    ("imperial ", VolumeSystem::Imperial),
+    ("metric ", VolumeSystem::Metric),
+    ("au ", VolumeSystem::Australian),
+    ("aus ", VolumeSystem::Australian),
+    ("australian ", VolumeSystem::Australian),
+];
+const SYSTEM_SUFFIXES: [(&str, VolumeSystem); 6] = [
+    ("_us", VolumeSystem::UsLiquid),
+    ("_us_dry", VolumeSystem::UsDry),
+    ("_uk", VolumeSystem::Imperial),
+    ("_imp", VolumeSystem::Imperial),
+    ("_metric", VolumeSystem::Metric),
+    ("_au", VolumeSystem::Australian),
+];
+
+/// Customary units qualified by their system, such as "uk gal", "us dry
+/// pints" or "cwt_uk". A unit the system lacks, such as the metric gallon,
+/// is not recognised.
+fn parse_system(token: &str) -> Option<Unit> {
+    let (system, rest) = SYSTEM_PREFIXES
+        .iter()
+        .find_map(|&(prefix, system)| Some((system, token.strip_prefix(prefix)?)))
+        .or_else(|| SYSTEM_SUFFIXES.iter().find_map(|&(suffix, system)| Some((system, token.strip_suffix(suffix)?))))?;
+
+    let unit = match (parse_alias(rest)?, system) {
+        (Unit::Volume(volume), _) => {
+            let volume = match volume {
+                VolUnit::TeaSpoon(_) => VolUnit::TeaSpoon(system),
+                VolUnit::TableSpoon(_) => VolUnit::TableSpoon(system),
+                VolUnit::FluidOunce(_) => VolUnit::FluidOunce(system),
+                VolUnit::Gill(_) => VolUnit::Gill(system),
+                VolUnit::Cup(_) => VolUnit::Cup(system),
+                VolUnit::Pint(_) => VolUnit::Pint(system),
+                VolUnit::Quart(_) => VolUnit::Quart(system),
+                VolUnit::Gallon(_) => VolUnit::Gallon(system),
+                VolUnit::Peck(_) => VolUnit::Peck(system),
+                VolUnit::Bushel(_) => VolUnit::Bushel(system),
+                _ => return None,
+            };
+            customary_volume(volume)?;
+            Unit::Volume(volume)
+        }
+        (Unit::Mass(MassUnit::ShortHundredweight), VolumeSystem::UsLiquid) => Unit::Mass(MassUnit::ShortHundredweight),
+        (Unit::Mass(MassUnit::ShortHundredweight), VolumeSystem::Imperial) => Unit::Mass(MassUnit::LongHundredweight),
+        (Unit::Mass(MassUnit::ShortTon), VolumeSystem::UsLiquid) => Unit::Mass(MassUnit::ShortTon),
+        (Unit::Mass(MassUnit::ShortTon), VolumeSystem::Imperial) => Unit::Mass(MassUnit::LongTon),
+        (Unit::Mass(MassUnit::ShortTon), VolumeSystem::Metric) => Unit::Mass(MassUnit::Tonne),
+        _ => return None,
+    };
+    Some(unit)
+}
+
+/// Prefixed metric names such as "millimeters" or "square kilometers".
+fn parse_metric_name(token: &str) -> Option<Unit> {
+    let (power, name) = if let Some(rest) = token.strip_prefix("square ") {
+        (2, rest)
+    } else if let Some(rest) = token.strip_prefix("cubic ") {
+        (3, rest)
+    } else {
+        (1, token)
+    };
+
+    // Deca is also spelled deka
+    let deka;
+    let name = match name.strip_prefix("deka") {
+        Some(rest) => {
+            deka = format!("deca{rest}");
+            deka.as_str()
+        }
+        None => name,
+    };
+
+    for scale in PREFIX_SCALES {
+        let Some(base) = scale_to_string(scale).and_then(|p| name.strip_prefix(p)) else {
+            continue;
+        };
+        let unit = match (power, base) {
+            (1, "meter" | "meters" | "metre" | "metres") => Unit::Length(LengthUnit::Meter(scale)),
+            (2, "meter" | "meters" | "metre" | "metres") => Unit::Area(AreaUnit::Meter2(scale)),
+            (3, "meter" | "meters" | "metre" | "metres") => Unit::Volume(VolUnit::Meter3(scale)),
+            (1, "liter" | "liters" | "litre" | "litres") => Unit::Volume(VolUnit::Liter(scale)),
+            (1, "gram" | "grams") => Unit::Mass(MassUnit::Gram(scale)),
+            (1, "meter per second" | "meters per second" | "metre per second" | "metres per second") => {
+                Unit::Speed(SpeedUnit::MeterPerSecond(scale))
+            }
+            (1, "second" | "seconds") => Unit::Time(TimeUnit::Second(scale)),
+            (1, "pascal" | "pascals") => Unit::Pressure(PressureUnit::Pascal(scale)),
+            (1, "bar" | "bars") => Unit::Pressure(PressureUnit::Bar(scale)),
+            (1, "joule" | "joules") => Unit::Energy(EnergyUnit::Joule(scale)),
+            (1, "watt-hour" | "watt-hours" | "watt hour" | "watt hours") => {
+                Unit::Energy(EnergyUnit::WattHour(scale))
+            }
+            (1, "calorie" | "calories") => Unit::Energy(EnergyUnit::Calorie(scale)),
+            (1, "electronvolt" | "electronvolts" | "electron volt" | "electron volts") => {
+                Unit::Energy(EnergyUnit::Electronvolt(scale))
+            }
+            (1, "watt" | "watts") => Unit::Power(PowerUnit::Watt(scale)),
+            (1, "bit" | "bits") if scale >= 0 => Unit::Data(DataUnit::Bit(scale)),
+            (1, "byte" | "bytes") if scale >= 0 => Unit::Data(DataUnit::Byte(scale)),
+            (1, "bit per second" | "bits per second") if scale >= 0 => {
+                Unit::DataRate(DataRateUnit::BitPerSecond(scale))
+            }
+            (1, "byte per second" | "bytes per second") if scale >= 0 => {
+                Unit::DataRate(DataRateUnit::BytePerSecond(scale))
+            }
+            (1, "radian" | "radians") => Unit::Angle(AngleUnit::Radian(scale)),
+            (1, "steradian" | "steradians") => Unit::SolidAngle(SolidAngleUnit::Steradian(scale)),
+            (1, "newton" | "newtons") => Unit::Force(ForceUnit::Newton(scale)),
+            (1, "hertz") => Unit::Frequency(FrequencyUnit::Hertz(scale)),
+            (1, "newton-meter" | "newton-meters" | "newton meter" | "newton meters" | "newton-metre"
+                | "newton-metres") => Unit::Torque(TorqueUnit::NewtonMeter(scale)),
+            (1, "mole per liter" | "moles per liter" | "mole per litre" | "moles per litre") => {
+                Unit::Concentration(ConcentrationUnit::MolePerLiter(scale))
+            }
+            (1, "coulomb" | "coulombs") => Unit::Charge(ChargeUnit::Coulomb(scale)),
+            (1, "ampere-hour" | "ampere-hours" | "ampere hour" | "ampere hours" | "amp-hour" | "amp-hours") => {
+                Unit::Charge(ChargeUnit::AmpereHour(scale))
+            }
+            (1, "ampere" | "amperes" | "amp" | "amps") => Unit::Current(CurrentUnit::Ampere(scale)),
+            (1, "volt" | "volts") => Unit::Voltage(VoltageUnit::Volt(scale)),
+            (1, "ohm" | "ohms") => Unit::Resistance(ResistanceUnit::Ohm(scale)),
+            (1, "siemens") => Unit::Conductance(ConductanceUnit::Siemens(scale)),
+            (1, "farad" | "farads") => Unit::Capacitance(CapacitanceUnit::Farad(scale)),
+            (1, "henry" | "henries" | "henrys") => Unit::Inductance(InductanceUnit::Henry(scale)),
+            (1, "tesla" | "teslas") => Unit::MagneticFluxDensity(MagneticFluxDensityUnit::Tesla(scale)),
+            (1, "weber" | "webers") => Unit::MagneticFlux(MagneticFluxUnit::Weber(scale)),
+            (1, "candela" | "candelas") => Unit::LuminousIntensity(LuminousIntensityUnit::Candela(scale)),
+            (1, "lumen" | "lumens") => Unit::LuminousFlux(LuminousFluxUnit::Lumen(scale)),
+            (1, "lux") => Unit::Illuminance(IlluminanceUnit::Lux(scale)),
+            (1, "becquerel" | "becquerels") => Unit::Radioactivity(RadioactivityUnit::Becquerel(scale)),
+            (1, "curie" | "curies") => Unit::Radioactivity(RadioactivityUnit::Curie(scale)),
+            (1, "gray" | "grays") => Unit::AbsorbedDose(AbsorbedDoseUnit::Gray(scale)),
+            // Only the plural, as "rad" is the radian
+            (1, "rads") => Unit::AbsorbedDose(AbsorbedDoseUnit::Rad(scale)),
+            (1, "sievert" | "sieverts") => Unit::EquivalentDose(EquivalentDoseUnit::Sievert(scale)),
+            (1, "rem" | "rems") => Unit::EquivalentDose(EquivalentDoseUnit::Rem(scale)),
+            (1, "meter per second squared" | "meters per second squared" | "metre per second squared"
+                | "metres per second squared") => Unit::Acceleration(AccelerationUnit::MeterPerSecond2(scale)),
+            (1, "gals" | "galileo" | "galileos") => Unit::Acceleration(AccelerationUnit::Gal(scale)),
+            (1, "mole" | "moles") => Unit::Amount(AmountUnit::Mole(scale)),
+            _ => continue,
+        };
+        return Some(unit);
+    }
+
+    None
+}
+
+/// Binary-prefixed data symbols such as "KiB", "Mibit" or "GiB/s". The
+/// prefix may be in any case, but "B" (byte) and "b" (bit) are kept apart.
+fn parse_binary_symbol(token: &str) -> Option<Unit> {
+    for scale in BINARY_PREFIX_SCALES {
+        let Some(prefix) = binary_scale_to_symbol(scale) else {
+            continue;
+        };
+        let Some(base) = token
+            .get(..prefix.len())
+            .filter(|p| p.eq_ignore_ascii_case(prefix))
+            .map(|_| &token[prefix.len()..])
+        else {
+            continue;
+        };
+        let unit = match base {
+            "b" | "bit" => Unit::Data(DataUnit::BinaryBit(scale)),
+            "B" => Unit::Data(DataUnit::BinaryByte(scale)),
+            "bit/s" | "b/s" | "bps" => Unit::DataRate(DataRateUnit::BinaryBitPerSecond(scale)),
+            "B/s" | "Bps" => Unit::DataRate(DataRateUnit::BinaryBytePerSecond(scale)),
+            _ => continue,
+        };
+        return Some(unit);
+    }
+
+    None
+}
+
+/// Binary-prefixed data names such as "kibibytes".
+fn parse_binary_name(token: &str) -> Option<Unit> {
+    for scale in BINARY_PREFIX_SCALES {
+        let Some(base) = binary_scale_to_string(scale).and_then(|p| token.strip_prefix(p)) else {
+            continue;
+        };
+        let unit = match base {
+            "bit" | "bits" => Unit::Data(DataUnit::BinaryBit(scale)),
+            "byte" | "bytes" => Unit::Data(DataUnit::BinaryByte(scale)),
+            "bit per second" | "bits per second" => Unit::DataRate(DataRateUnit::BinaryBitPerSecond(scale)),
+            "byte per second" | "bytes per second" => Unit::DataRate(DataRateUnit::BinaryBytePerSecond(scale)),
+            _ => continue,
+        };
+        return Some(unit);
+    }
+
+    None
+}
+
+/// Units built from two others, such as "kg/m3", "pounds per gallon" or
+/// "L/min".
+fn parse_per(token: &str) -> Option<Unit> {
+    let token = token.replace('³', "3");
+    let (numerator, denominator) = token.split_once('/').or_else(|| token.split_once(" per "))?;
+    match (numerator.parse().ok()?, denominator.parse().ok()?) {
+        (Unit::Mass(mass), Unit::Volume(vol)) => Some(Unit::Density(DensityUnit::MassPerVolume(mass, vol))),
+        (Unit::Volume(vol), Unit::Time(time)) => Some(Unit::FlowRate(FlowRateUnit::VolumePerTime(vol, time))),
+        (Unit::Mass(mass), Unit::Time(time)) => Some(Unit::MassFlow(MassFlowUnit::MassPerTime(mass, time))),
+        _ => None,
+    }
+}
@@ -1,11 +1,57 @@
 use unit_converter::{
     convert,
+    convert_exact,
+    convert_real,
+    molar_mass,
+    try_convert,
+    try_convert_real,
+    try_convert_wavelength,
+    ConversionContext,
+    ConversionError,
+    Dimension,
+    Ratio,
     Unit::*,
     TempUnit::*,
     LengthUnit::*,
     AreaUnit::*,
     VolUnit::*,
+    VolumeSystem::*,
     MassUnit::*,
+    SpeedUnit::*,
+    TimeUnit::*,
+    PressureUnit::*,
+    EnergyUnit::*,
+    PowerUnit::*,
+    DataUnit::*,
+    DataRateUnit::*,
+    AngleUnit::*,
+    SolidAngleUnit::*,
+    ForceUnit::*,
+    TorqueUnit::*,
+    FrequencyUnit::*,
+    FuelEconomyUnit::*,
+    DensityUnit::*,
+    ConcentrationUnit::*,
+    FlowRateUnit::*,
+    MassFlowUnit::*,
+    ChargeUnit::*,
+    CurrentUnit::*,
+    VoltageUnit::*,
+    ResistanceUnit::*,
+    ConductanceUnit::*,
+    CapacitanceUnit::*,
+    InductanceUnit::*,
+    MagneticFluxDensityUnit::*,
+    MagneticFluxUnit::*,
+    LuminousIntensityUnit::*,
+    LuminousFluxUnit::*,
+    IlluminanceUnit::*,
+    LuminanceUnit::*,
+    RadioactivityUnit::*,
+    AbsorbedDoseUnit::*,
+    EquivalentDoseUnit::*,
+    AccelerationUnit::*,
+    AmountUnit::*,
 };
 
 #[test]
@@ -57,6 +103,81 @@ fn test_conversion_area() {
     assert!((m2 - 10_000.0).abs() < 1e-3);
 }
 
+#[test]
+fn test_conversion_length_surveying() {
+    // The survey foot is exactly 1200/3937 m, 2 ppm longer than the
+    // international foot
+    assert_eq!(convert_exact(Ratio::ONE, Length(SurveyFoot), Length(Meter(0))), Ok(Ratio::new(1200, 3937)));
+    assert_eq!(convert_exact(Ratio::ONE, Length(SurveyFoot), Length(Feet)), Ok(Ratio::new(500_000, 499_999)));
+    assert_eq!(convert_exact(Ratio::ONE, Length(SurveyMile), Length(SurveyFoot)), Ok(Ratio::from(5280)));
+
+    assert_eq!(convert_exact(Ratio::ONE, Length(Chain), Length(Link)), Ok(Ratio::from(100)));
+    assert_eq!(convert_exact(Ratio::ONE, Length(Chain), Length(Rod)), Ok(Ratio::from(4)));
+    assert_eq!(convert_exact(Ratio::ONE, Length(Furlong), Length(Chain)), Ok(Ratio::from(10)));
+    assert_eq!(convert_exact(Ratio::from(8), Length(Furlong), Length(Mile)), Ok(Ratio::ONE));
+    assert_eq!(convert_exact(Ratio::ONE, Length(League), Length(Mile)), Ok(Ratio::from(3)));
+    assert_eq!(convert_exact(Ratio::ONE, Length(Fathom), Length(Feet)), Ok(Ratio::from(6)));
+    assert_eq!(convert_exact(Ratio::ONE, Length(NauticalMile), Length(Meter(0))), Ok(Ratio::from(1852)));
+    assert_eq!(convert_exact(Ratio::from(1000), Length(Mil), Length(Inch)), Ok(Ratio::ONE));
+}
+
+#[test]
+fn test_conversion_length_astronomical() {
+    assert_eq!(convert_exact(Ratio::ONE, Length(Angstrom), Length(Meter(-9))), Ok(Ratio::new(1, 10)));
+    assert_eq!(
+        convert_exact(Ratio::ONE, Length(AstronomicalUnit), Length(Meter(0))),
+        Ok(Ratio::from(149_597_870_700i64))
+    );
+    // A light-year is a Julian year at the speed of light
+    let ly = convert(1.0, Length(LightYear), Length(Meter(0)));
+    let light = convert(1.0, Time(JulianYear), Time(Second(0))) * 299_792_458.0;
+    assert_eq!(ly, light);
+
+    // A parsec is 648000/π au, about 3.26 light-years
+    let au = convert(1.0, Length(Parsec), Length(AstronomicalUnit));
+    assert!((au - 648_000.0 / std::f64::consts::PI).abs() < 1e-6);
+    let ly = convert(1.0, Length(Parsec), Length(LightYear));
+    assert!((ly - 3.261_563_777).abs() < 1e-9);
+    assert_eq!(convert_exact(Ratio::ONE, Length(Parsec), Length(LightYear)), Err(ConversionError::Inexact));
+}
+
+#[test]
+fn test_conversion_typographic() {
+    assert_eq!(convert_exact(Ratio::from(72), Length(Point), Length(Inch)), Ok(Ratio::ONE));
+    assert_eq!(convert_exact(Ratio::from(6), Length(Pica), Length(Inch)), Ok(Ratio::ONE));
+    assert_eq!(convert_exact(Ratio::ONE, Length(Point), Length(Twip)), Ok(Ratio::from(20)));
+    assert_eq!(convert_exact(Ratio::ONE, Length(Cicero), Length(DidotPoint)), Ok(Ratio::from(12)));
+    assert_eq!(convert_exact(Ratio::ONE, Length(DidotPoint), Length(Meter(-6))), Ok(Ratio::from(376)));
+
+    // The CSS reference pixel, and 12 pt type, by default
+    assert_eq!(convert_exact(Ratio::ONE, Length(Inch), Length(Pixel)), Ok(Ratio::from(96)));
+    assert_eq!(convert_exact(Ratio::ONE, Length(Em), Length(Pixel)), Ok(Ratio::from(16)));
+    assert_eq!(convert(1.0, Length(Em), Length(Point)), 12.0);
+
+    let context = ConversionContext { dpi: Ratio::from(300), font_size: Ratio::new(21, 2), ..Default::default() };
+    assert_eq!(context.convert_exact(Ratio::from(300), Length(Pixel), Length(Inch)), Ok(Ratio::ONE));
+    assert_eq!(context.convert_exact(Ratio::from(2), Length(Em), Length(Point)), Ok(Ratio::from(21)));
+    let mm = context.try_convert(1.0, Length(Pixel), Length(Meter(-3))).unwrap();
+    assert!((mm - 25.4 / 300.0).abs() < 1e-12);
+
+    // ...in every kind of conversion
+    let thz = context.try_convert_wavelength(1.0, Length(Pixel), Frequency(Hertz(12))).unwrap();
+    assert!((thz - 3.540_855_803).abs() < 1e-6);
+
+    // Physical units do not depend on the resolution
+    assert_eq!(context.convert(1.0, Length(Point), Length(Inch)), convert(1.0, Length(Point), Length(Inch)));
+
+    let context = ConversionContext { dpi: Ratio::ZERO, font_size: Ratio::from(-1), ..Default::default() };
+    assert_eq!(context.try_convert(1.0, Length(Pixel), Length(Inch)), Err(ConversionError::InvalidDpi));
+    assert_eq!(context.try_convert(1.0, Length(Inch), Length(Em)), Err(ConversionError::InvalidFontSize));
+    assert_eq!(context.try_convert(1.0, Length(Inch), Length(Point)), Ok(72.0));
+    assert!(context.convert(1.0, Length(Pixel), Length(Inch)).is_nan());
+
+    // A font size too large for the exact factor is refused, not a panic
+    let context = ConversionContext { font_size: Ratio::from(i128::MAX / 2), ..Default::default() };
+    assert_eq!(context.try_convert(1.0, Length(Em), Length(Point)), Err(ConversionError::Overflow));
+}
+
 #[test]
 fn test_conversion_volume() {
     // 1 liter = 1000 ml
@@ -68,14 +189,63 @@ fn test_conversion_volume() {
     assert!((l - 1000.0).abs() < 1e-3);
 
     // 1 cup ≈ 0.236588 liters
-    let l = convert(1.0, Volume(Cup), Volume(Liter(0)));
+    let l = convert(1.0, Volume(Cup(UsLiquid)), Volume(Liter(0)));
     assert!((l - 0.2365882365).abs() < 1e-3);
 
     // 1 gallon ≈ 3.78541 liters
-    let l = convert(1.0, Volume(Gallon), Volume(Liter(0)));
+    let l = convert(1.0, Volume(Gallon(UsLiquid)), Volume(Liter(0)));
     assert!((l - 3.785411784).abs() < 1e-3);
 }
 
+#[test]
+fn test_conversion_volume_systems() {
+    // The imperial gallon is defined in liters, the US ones in cubic inches
+    assert_eq!(
+        convert_exact(Ratio::ONE, Volume(Gallon(Imperial)), Volume(Liter(-3))),
+        Ok(Ratio::new(454_609, 100))
+    );
+    assert_eq!(convert_exact(Ratio::ONE, Volume(Gallon(UsLiquid)), Volume(Inch3)), Ok(Ratio::from(231)));
+    assert_eq!(
+        convert_exact(Ratio::ONE, Volume(Bushel(UsDry)), Volume(Inch3)),
+        Ok(Ratio::new(215_042, 100))
+    );
+
+    // 20 imperial and 16 US fluid ounces to the pint
+    assert_eq!(convert_exact(Ratio::ONE, Volume(Pint(Imperial)), Volume(FluidOunce(Imperial))), Ok(Ratio::from(20)));
+    assert_eq!(convert_exact(Ratio::ONE, Volume(Pint(UsLiquid)), Volume(FluidOunce(UsLiquid))), Ok(Ratio::from(16)));
+    assert_eq!(convert_exact(Ratio::ONE, Volume(Quart(UsDry)), Volume(Pint(UsDry))), Ok(Ratio::from(2)));
+    assert_eq!(convert_exact(Ratio::ONE, Volume(Bushel(Imperial)), Volume(Peck(Imperial))), Ok(Ratio::from(4)));
+    assert_eq!(convert_exact(Ratio::ONE, Volume(Gill(UsLiquid)), Volume(Cup(UsLiquid))), Ok(Ratio::new(1, 2)));
+
+    // Spoons
+    assert_eq!(
+        convert_exact(Ratio::ONE, Volume(TableSpoon(Australian)), Volume(TeaSpoon(Metric))),
+        Ok(Ratio::from(4))
+    );
+    assert_eq!(
+        convert_exact(Ratio::ONE, Volume(TableSpoon(UsLiquid)), Volume(TeaSpoon(UsLiquid))),
+        Ok(Ratio::from(3))
+    );
+    let ml = convert(1.0, Volume(TableSpoon(UsLiquid)), Volume(Liter(-3)));
+    assert!((ml - 14.786_764_781_25).abs() < 1e-9);
+    assert_eq!(convert_exact(Ratio::ONE, Volume(Cup(Metric)), Volume(Liter(-3))), Ok(Ratio::from(250)));
+
+    // Units a system does not have
+    assert_eq!(
+        try_convert(1.0, Volume(Gallon(Metric)), Volume(Liter(0))),
+        Err(ConversionError::UnsupportedSystem(Volume(Gallon(Metric))))
+    );
+    assert_eq!(
+        try_convert(1.0, Density(MassPerVolume(Pound, Bushel(UsLiquid))), Density(SpecificGravity)),
+        Err(ConversionError::UnsupportedSystem(Volume(Bushel(UsLiquid))))
+    );
+    assert!(convert(1.0, Volume(Liter(0)), Volume(TableSpoon(UsDry))).is_nan());
+    assert_eq!(
+        ConversionError::UnsupportedSystem(Volume(Gallon(Metric))).to_string(),
+        "There are no metric gallons"
+    );
+}
+
 #[test]
 fn test_conversion_mass() {
     // 1 kg = 1000 g
@@ -93,4 +263,899 @@ fn test_conversion_mass() {
     // 1 stone = 14 pounds = 6350.29318 g
     let g = convert(1.0, Mass(Stone), Mass(Gram(0)));
     assert!((g - 6350.29318).abs() < 1e-3);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_conversion_tons() {
+    assert_eq!(convert_exact(Ratio::ONE, Mass(ShortTon), Mass(Pound)), Ok(Ratio::from(2000)));
+    assert_eq!(convert_exact(Ratio::ONE, Mass(LongTon), Mass(Pound)), Ok(Ratio::from(2240)));
+    assert_eq!(convert_exact(Ratio::from(20), Mass(ShortHundredweight), Mass(ShortTon)), Ok(Ratio::ONE));
+    assert_eq!(convert_exact(Ratio::from(20), Mass(LongHundredweight), Mass(LongTon)), Ok(Ratio::ONE));
+    assert_eq!(convert_exact(Ratio::ONE, Mass(Tonne), Mass(Gram(3))), Ok(Ratio::from(1000)));
+    assert_eq!(convert_exact(Ratio::ONE, Mass(LongTon), Mass(Gram(3))), Ok(Ratio::new(10_160_469_088, 10_000_000)));
+}
+
+#[test]
+fn test_conversion_troy_and_apothecaries() {
+    assert_eq!(convert_exact(Ratio::ONE, Mass(TroyOunce), Mass(Gram(0))), Ok(Ratio::new(311_034_768, 10_000_000)));
+    assert_eq!(convert_exact(Ratio::ONE, Mass(TroyOunce), Mass(Grain)), Ok(Ratio::from(480)));
+    assert_eq!(convert_exact(Ratio::ONE, Mass(Pound), Mass(Grain)), Ok(Ratio::from(7000)));
+    assert_eq!(convert_exact(Ratio::ONE, Mass(TroyPound), Mass(TroyOunce)), Ok(Ratio::from(12)));
+    assert_eq!(convert_exact(Ratio::ONE, Mass(TroyOunce), Mass(Pennyweight)), Ok(Ratio::from(20)));
+    assert_eq!(convert_exact(Ratio::ONE, Mass(ApothecaryDram), Mass(Scruple)), Ok(Ratio::from(3)));
+    assert_eq!(convert_exact(Ratio::ONE, Mass(TroyOunce), Mass(ApothecaryDram)), Ok(Ratio::from(8)));
+    assert_eq!(convert_exact(Ratio::ONE, Mass(Ounce), Mass(Dram)), Ok(Ratio::from(16)));
+    assert_eq!(convert_exact(Ratio::from(5), Mass(Carat), Mass(Gram(0))), Ok(Ratio::ONE));
+
+    // An avoirdupois ounce is lighter than a troy ounce
+    assert_eq!(
+        convert_exact(Ratio::ONE, Mass(Ounce), Mass(TroyOunce)),
+        Ok(Ratio::new(175, 192))
+    );
+
+    // A pound-force gives a slug 1 ft/s²
+    let kg = convert(1.0, Mass(Slug), Mass(Gram(3)));
+    assert!((kg - 14.593_902_937).abs() < 1e-9);
+}
+
+#[test]
+fn test_try_convert() {
+    // Same dimension behaves like convert
+    let ft = try_convert(1.0, Length(Meter(0)), Length(Feet)).unwrap();
+    assert!((ft - 3.28084).abs() < 1e-3);
+
+    // Kelvin to miles is meaningless
+    assert_eq!(
+        try_convert(1.0, Temperature(Kelvin), Length(Mile)),
+        Err(ConversionError::IncompatibleDimensions(Dimension::Temperature, Dimension::Length))
+    );
+
+    // NaN and infinity are rejected
+    assert_eq!(
+        try_convert(f64::NAN, Mass(Pound), Mass(Gram(0))),
+        Err(ConversionError::NonFiniteValue)
+    );
+    assert_eq!(
+        try_convert(f64::INFINITY, Mass(Pound), Mass(Gram(0))),
+        Err(ConversionError::NonFiniteValue)
+    );
+
+    // Overflowing the numeric range is an error, not infinity
+    assert_eq!(
+        try_convert(f64::MAX, Area(Mile2), Area(Meter2(-3))),
+        Err(ConversionError::NonFiniteResult)
+    );
+
+    // Scales without a metric prefix are rejected
+    assert_eq!(
+        try_convert(1.0, Length(Meter(5)), Length(Meter(0))),
+        Err(ConversionError::UnsupportedScale(5))
+    );
+}
+
+#[test]
+fn test_precision() {
+    // 1 mi² = 2,589,988,110,336 mm², and f64 keeps every digit
+    let mm2 = convert(1.0, Area(Mile2), Area(Meter2(-3)));
+    assert!((mm2 - 2_589_988_110_336.0).abs() < 1e-2);
+
+    // f32 still works, at f32 precision
+    let mm2: f32 = convert_real(1.0, Area(Mile2), Area(Meter2(-3)));
+    assert!((mm2 / 2.589_988e12 - 1.0).abs() < 1e-6);
+
+    let ft = try_convert_real(1.0f32, Length(Meter(0)), Length(Feet)).unwrap();
+    assert!((ft - 3.28084).abs() < 1e-3);
+    assert_eq!(
+        try_convert_real(f32::MAX, Length(Meter(3)), Length(Meter(-3))),
+        Err(ConversionError::NonFiniteResult)
+    );
+}
+
+#[test]
+fn test_conversion_exact() {
+    // 1 mi² = 2,589,988,110,336 mm², exactly
+    let mm2 = convert_exact(Ratio::from(1), Area(Mile2), Area(Meter2(-3))).unwrap();
+    assert_eq!(mm2, Ratio::from(2_589_988_110_336i64));
+
+    // 1 inch = 127/50 cm, and back again
+    let cm = convert_exact(Ratio::from(1), Length(Inch), Length(Meter(-2))).unwrap();
+    assert_eq!(cm, Ratio::new(127, 50));
+    let inch = convert_exact(cm, Length(Meter(-2)), Length(Inch)).unwrap();
+    assert_eq!(inch, Ratio::from(1));
+
+    // Offsets are exact too: 98.6 °F = 37 °C
+    let f = "98.6".parse().unwrap();
+    let c = convert_exact(f, Temperature(Fahrenheit), Temperature(Celsius)).unwrap();
+    assert_eq!(c, Ratio::from(37));
+
+    // Metric-imperial round trips never drift
+    let gal = Ratio::new(7, 3);
+    let ml = convert_exact(gal, Volume(Gallon(UsLiquid)), Volume(Liter(-3))).unwrap();
+    assert_eq!(convert_exact(ml, Volume(Liter(-3)), Volume(Gallon(UsLiquid))), Ok(gal));
+
+    assert_eq!(
+        convert_exact(Ratio::from(1), Mass(Pound), Length(Feet)),
+        Err(ConversionError::IncompatibleDimensions(Dimension::Mass, Dimension::Length))
+    );
+}
+
+#[test]
+fn test_conversion_si_prefixes() {
+    // 1 µm = 1000 nm
+    let nm = convert(1.0, Length(Meter(-6)), Length(Meter(-9)));
+    assert!((nm - 1000.0).abs() < 1e-9);
+
+    // 1 Mg = 1 metric ton = 1,000,000 g
+    let g = convert(1.0, Mass(Gram(6)), Mass(Gram(0)));
+    assert!((g - 1e6).abs() < 1e-6);
+
+    // 1 hm² = 1 hectare
+    let ha = convert(1.0, Area(Meter2(2)), Area(Hectare));
+    assert!((ha - 1.0).abs() < 1e-12);
+
+    // 1 dm³ = 1 L
+    let l = convert_exact(Ratio::from(1), Volume(Meter3(-1)), Volume(Liter(0)));
+    assert_eq!(l, Ok(Ratio::from(1)));
+
+    // Extreme prefixes overflow the exact path, but not f64
+    let qm3 = convert(1.0, Volume(Meter3(30)), Volume(Meter3(-30)));
+    assert!((qm3 / 1e180 - 1.0).abs() < 1e-9);
+    assert_eq!(
+        convert_exact(Ratio::from(1), Volume(Meter3(30)), Volume(Meter3(-30))),
+        Err(ConversionError::Overflow)
+    );
+}
+
+#[test]
+fn test_conversion_speed() {
+    // 100 km/h ≈ 27.7778 m/s
+    let ms = convert(100.0, Speed(KilometerPerHour), Speed(MeterPerSecond(0)));
+    assert!((ms - 27.777_778).abs() < 1e-3);
+
+    // 60 mph ≈ 96.5606 km/h
+    let kmh = convert(60.0, Speed(MilePerHour), Speed(KilometerPerHour));
+    assert!((kmh - 96.560_64).abs() < 1e-3);
+
+    // 1 knot = 1852 m/h exactly
+    let kmh = convert_exact(Ratio::from(1), Speed(Knot), Speed(KilometerPerHour));
+    assert_eq!(kmh, Ok(Ratio::new(1852, 1000)));
+
+    // Mach 1 at sea level ≈ 1225 km/h
+    let kmh = convert(1.0, Speed(Mach), Speed(KilometerPerHour));
+    assert!((kmh - 1225.058).abs() < 1e-2);
+
+    // 0.5 c ≈ 149,896.229 km/s
+    let kms = convert(0.5, Speed(SpeedOfLight), Speed(MeterPerSecond(3)));
+    assert!((kms - 149_896.229).abs() < 1e-3);
+
+    // Speed is not length
+    assert!(try_convert(1.0, Speed(MilePerHour), Length(Mile)).is_err());
+}
+
+#[test]
+fn test_conversion_time() {
+    // 1 hour = 3600 s
+    let s = convert(1.0, Time(Hour), Time(Second(0)));
+    assert!((s - 3600.0).abs() < 1e-9);
+
+    // 1 ps = 1000 fs
+    assert_eq!(convert_exact(Ratio::ONE, Time(Second(-12)), Time(Second(-15))), Ok(Ratio::from(1000)));
+
+    // 1 week = 7 days
+    let d = convert(1.0, Time(Week), Time(Day));
+    assert!((d - 7.0).abs() < 1e-9);
+
+    // 1 Julian year = 365.25 days, exactly
+    let d = convert_exact(Ratio::from(1), Time(JulianYear), Time(Day));
+    assert_eq!(d, Ok(Ratio::new(36525, 100)));
+
+    // 1 ms = 1000 µs
+    let us = convert(1.0, Time(Second(-3)), Time(Second(-6)));
+    assert!((us - 1000.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_conversion_pressure() {
+    // 1 atm = 101.325 kPa
+    let kpa = convert(1.0, Pressure(Atmosphere), Pressure(Pascal(3)));
+    assert!((kpa - 101.325).abs() < 1e-9);
+
+    // 1 bar = 1000 mbar = 100 kPa
+    let mbar = convert(1.0, Pressure(Bar(0)), Pressure(Bar(-3)));
+    assert!((mbar - 1000.0).abs() < 1e-9);
+
+    // 1 atm ≈ 14.6959 psi ≈ 29.9213 inHg
+    let psi = convert(1.0, Pressure(Atmosphere), Pressure(Psi));
+    assert!((psi - 14.695_949).abs() < 1e-5);
+    let inhg = convert(1.0, Pressure(Atmosphere), Pressure(InchOfMercury));
+    assert!((inhg - 29.921_25).abs() < 1e-4);
+
+    // 760 Torr = 1 atm exactly, and 1 Torr is just shy of 1 mmHg
+    let atm = convert_exact(Ratio::from(760), Pressure(Torr), Pressure(Atmosphere));
+    assert_eq!(atm, Ok(Ratio::from(1)));
+    let mmhg = convert(1.0, Pressure(Torr), Pressure(MillimeterOfMercury));
+    assert!((mmhg - 0.999_999_857).abs() < 1e-9);
+
+    // 1 kgf/cm² = 98.0665 kPa
+    let kpa = convert(1.0, Pressure(KilogramForcePerCm2), Pressure(Pascal(3)));
+    assert!((kpa - 98.0665).abs() < 1e-9);
+}
+
+#[test]
+fn test_conversion_gauge_pressure() {
+    // A flat tyre reads 0 psig, which is one atmosphere absolute
+    let psi = convert(0.0, Pressure(Psig), Pressure(Psi));
+    assert!((psi - 14.695_949).abs() < 1e-5);
+
+    // 32 psig ≈ 46.7 psi ≈ 322 kPa absolute
+    let kpa = convert(32.0, Pressure(Psig), Pressure(Pascal(3)));
+    assert!((kpa - 321.957_2).abs() < 1e-3);
+
+    // Absolute vacuum is one atmosphere below gauge zero
+    let psig = convert(0.0, Pressure(Pascal(0)), Pressure(Psig));
+    assert!((psig + 14.695_949).abs() < 1e-5);
+
+    // The reference atmosphere is configurable, e.g. at altitude
+    let context = ConversionContext { reference_pressure: Ratio::from(80_000), ..Default::default() };
+    let kpa = context.convert(0.0, Pressure(Psig), Pressure(Pascal(3)));
+    assert!((kpa - 80.0).abs() < 1e-9);
+    let kpa = context.try_convert(10.0, Pressure(Psig), Pressure(Pascal(3))).unwrap();
+    assert!((kpa - 148.947_57).abs() < 1e-3);
+
+    // ...and exact
+    let pa = context.convert_exact(Ratio::ZERO, Pressure(Psig), Pressure(Pascal(0)));
+    assert_eq!(pa, Ok(Ratio::from(80_000)));
+}
+
+#[test]
+fn test_conversion_energy() {
+    // 1 kWh = 3.6 MJ
+    let mj = convert(1.0, Energy(WattHour(3)), Energy(Joule(6)));
+    assert!((mj - 3.6).abs() < 1e-12);
+
+    // 1 kcal = 1 food Calorie = 4184 J
+    let j = convert_exact(Ratio::from(1), Energy(Calorie(3)), Energy(Joule(0)));
+    assert_eq!(j, Ok(Ratio::from(4184)));
+    let cal = convert(1.0, Energy(FoodCalorie), Energy(Calorie(3)));
+    assert!((cal - 1.0).abs() < 1e-12);
+
+    // IT calories are slightly larger than thermochemical ones
+    let cal = convert(1.0, Energy(CalorieIt), Energy(Calorie(0)));
+    assert!((cal - 1.000_669).abs() < 1e-6);
+    let kcal = convert(1.0, Energy(KilocalorieIt), Energy(CalorieIt));
+    assert!((kcal - 1000.0).abs() < 1e-9);
+
+    // 1 BTU (IT) = 1055.05585262 J exactly, and 1 BTU (th) ≈ 1054.35 J
+    let j = convert_exact(Ratio::from(1), Energy(BtuIt), Energy(Joule(0)));
+    assert_eq!(j, Ok("1055.05585262".parse().unwrap()));
+    let j = convert(1.0, Energy(BtuThermochemical), Energy(Joule(0)));
+    assert!((j - 1054.350264).abs() < 1e-5);
+
+    // 1 therm = 100,000 BTU
+    let btu = convert(1.0, Energy(Therm), Energy(BtuIt));
+    assert!((btu - 100_000.0).abs() < 1e-6);
+
+    // 1 MeV ≈ 1.602e-13 J
+    let j = convert(1.0, Energy(Electronvolt(6)), Energy(Joule(0)));
+    assert!((j / 1.602_176_634e-13 - 1.0).abs() < 1e-12);
+
+    // 1 erg = 100 nJ
+    let nj = convert(1.0, Energy(Erg), Energy(Joule(-9)));
+    assert!((nj - 100.0).abs() < 1e-9);
+
+    // 1 ft·lbf ≈ 1.355818 J
+    let j = convert(1.0, Energy(FootPound), Energy(Joule(0)));
+    assert!((j - 1.355_818).abs() < 1e-6);
+}
+
+#[test]
+fn test_conversion_power() {
+    // 1 hp = 550 ft·lbf/s ≈ 745.7 W, and exactly so as a fraction
+    let w = convert(1.0, Power(HorsepowerMechanical), Power(Watt(0)));
+    assert!((w - 745.699_872).abs() < 1e-6);
+    let w = convert_exact(Ratio::from(1), Power(HorsepowerMechanical), Power(Watt(0)));
+    assert_eq!(w, Ok("745.69987158227022".parse().unwrap()));
+
+    // 1 PS = 735.49875 W, and 1 electrical hp = 746 W
+    let w = convert_exact(Ratio::from(1), Power(HorsepowerMetric), Power(Watt(0)));
+    assert_eq!(w, Ok("735.49875".parse().unwrap()));
+    let kw = convert(1.0, Power(HorsepowerElectrical), Power(Watt(3)));
+    assert!((kw - 0.746).abs() < 1e-12);
+    let w = convert(1.0, Power(HorsepowerBoiler), Power(Watt(0)));
+    assert!((w - 9809.5).abs() < 1e-9);
+
+    // 1 ton of refrigeration = 12,000 BTU/h ≈ 3.517 kW
+    let btu_h = convert(1.0, Power(TonOfRefrigeration), Power(BtuPerHour));
+    assert!((btu_h - 12_000.0).abs() < 1e-6);
+    let kw = convert(1.0, Power(TonOfRefrigeration), Power(Watt(3)));
+    assert!((kw - 3.516_853).abs() < 1e-6);
+
+    // 1 erg/s = 100 nW
+    let nw = convert(1.0, Power(ErgPerSecond), Power(Watt(-9)));
+    assert!((nw - 100.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_conversion_decibel_power() {
+    // 0 dBm = 1 mW, 30 dBm = 1 W, -30 dBm = 1 µW
+    let mw = convert(0.0, Power(DecibelMilliwatt), Power(Watt(-3)));
+    assert!((mw - 1.0).abs() < 1e-12);
+    let w = convert(30.0, Power(DecibelMilliwatt), Power(Watt(0)));
+    assert!((w - 1.0).abs() < 1e-12);
+    let uw = convert(-30.0, Power(DecibelMilliwatt), Power(Watt(-6)));
+    assert!((uw - 1.0).abs() < 1e-12);
+
+    // 2 kW ≈ 63 dBm ≈ 33 dBW
+    let dbm = convert(2.0, Power(Watt(3)), Power(DecibelMilliwatt));
+    assert!((dbm - 63.010_300).abs() < 1e-6);
+    let dbw = convert(2.0, Power(Watt(3)), Power(DecibelWatt));
+    assert!((dbw - 33.010_300).abs() < 1e-6);
+
+    // dBW and dBm are 30 dB apart
+    let dbm = convert(-3.0, Power(DecibelWatt), Power(DecibelMilliwatt));
+    assert!((dbm - 27.0).abs() < 1e-9);
+
+    // Zero or negative power has no level in decibels
+    assert_eq!(
+        try_convert(0.0, Power(Watt(0)), Power(DecibelMilliwatt)),
+        Err(ConversionError::NonFiniteResult)
+    );
+    assert_eq!(
+        try_convert(-1.0, Power(Watt(0)), Power(DecibelWatt)),
+        Err(ConversionError::NonFiniteResult)
+    );
+
+    // Logarithmic units have no exact fractional form
+    assert_eq!(
+        convert_exact(Ratio::from(10), Power(DecibelMilliwatt), Power(Watt(-3))),
+        Err(ConversionError::Inexact)
+    );
+}
+
+#[test]
+fn test_conversion_data() {
+    // 1 byte = 8 bits = 2 nibbles
+    let bits = convert(1.0, Data(Byte(0)), Data(Bit(0)));
+    assert!((bits - 8.0).abs() < 1e-12);
+    let nibbles = convert(1.0, Data(Byte(0)), Data(Nibble));
+    assert!((nibbles - 2.0).abs() < 1e-12);
+
+    // SI prefixes are powers of ten, IEC ones powers of two
+    let b = convert_exact(Ratio::from(1), Data(Byte(6)), Data(Byte(0)));
+    assert_eq!(b, Ok(Ratio::from(1_000_000)));
+    let b = convert_exact(Ratio::from(1), Data(BinaryByte(20)), Data(Byte(0)));
+    assert_eq!(b, Ok(Ratio::from(1_048_576)));
+    let kib = convert(1.0, Data(BinaryByte(20)), Data(BinaryByte(10)));
+    assert!((kib - 1024.0).abs() < 1e-9);
+
+    // A "1 TB" drive holds about 931 GiB
+    let gib = convert(1.0, Data(Byte(12)), Data(BinaryByte(30)));
+    assert!((gib - 931.322_574_6).abs() < 1e-6);
+
+    // 1 Kibit = 128 B
+    let b = convert_exact(Ratio::from(1), Data(BinaryBit(10)), Data(Byte(0)));
+    assert_eq!(b, Ok(Ratio::from(128)));
+
+    // Yobi is the largest binary prefix and is still exact
+    let b = convert_exact(Ratio::from(1), Data(BinaryByte(80)), Data(Byte(0)));
+    assert_eq!(b, Ok(Ratio::from(1i128 << 80)));
+
+    // Only multiples of ten up to 80 have a binary prefix
+    assert_eq!(
+        try_convert(1.0, Data(BinaryByte(15)), Data(Byte(0))),
+        Err(ConversionError::UnsupportedBinaryScale(15))
+    );
+    assert_eq!(
+        try_convert(1.0, Data(Byte(0)), Data(BinaryByte(90))),
+        Err(ConversionError::UnsupportedBinaryScale(90))
+    );
+}
+
+#[test]
+fn test_conversion_data_rate() {
+    // 100 Mbit/s = 12.5 MB/s
+    let mb_s = convert(100.0, DataRate(BitPerSecond(6)), DataRate(BytePerSecond(6)));
+    assert!((mb_s - 12.5).abs() < 1e-12);
+
+    // 1 Gbit/s ≈ 119.2 MiB/s
+    let mib_s = convert(1.0, DataRate(BitPerSecond(9)), DataRate(BinaryBytePerSecond(20)));
+    assert!((mib_s - 119.209_289_6).abs() < 1e-6);
+
+    // 1 MiB/s = 8,388,608 bit/s exactly
+    let bps = convert_exact(Ratio::from(1), DataRate(BinaryBytePerSecond(20)), DataRate(BitPerSecond(0)));
+    assert_eq!(bps, Ok(Ratio::from(8_388_608)));
+
+    // Amounts of data are not rates
+    assert_eq!(
+        try_convert(1.0, Data(Byte(0)), DataRate(BytePerSecond(0))),
+        Err(ConversionError::IncompatibleDimensions(Dimension::Data, Dimension::DataRate))
+    );
+}
+
+#[test]
+fn test_conversion_angle() {
+    // 180° = π rad
+    let rad = convert(180.0, Angle(Degree), Angle(Radian(0)));
+    assert!((rad - std::f64::consts::PI).abs() < 1e-15);
+    let mrad = convert(1.0, Angle(Degree), Angle(Radian(-3)));
+    assert!((mrad - 17.453_292_5).abs() < 1e-6);
+
+    // Units that are all fractions of a turn convert exactly
+    let arcsec = convert_exact(Ratio::from(1), Angle(Degree), Angle(Arcsecond));
+    assert_eq!(arcsec, Ok(Ratio::from(3600)));
+    let gon = convert_exact(Ratio::from(90), Angle(Degree), Angle(Gradian));
+    assert_eq!(gon, Ok(Ratio::from(100)));
+    let mils = convert_exact(Ratio::from(1), Angle(Turn), Angle(MilNato));
+    assert_eq!(mils, Ok(Ratio::from(6400)));
+    let mils = convert_exact(Ratio::from(1), Angle(Turn), Angle(MilWarsawPact));
+    assert_eq!(mils, Ok(Ratio::from(6000)));
+    let mils = convert_exact(Ratio::from(1), Angle(Turn), Angle(MilSwedish));
+    assert_eq!(mils, Ok(Ratio::from(6300)));
+
+    // ...but radians are an irrational number of degrees
+    assert_eq!(
+        convert_exact(Ratio::from(1), Angle(Radian(0)), Angle(Degree)),
+        Err(ConversionError::Inexact)
+    );
+
+    // A NATO mil is close to, but not quite, a milliradian
+    let mrad = convert(1.0, Angle(MilNato), Angle(Radian(-3)));
+    assert!((mrad - 0.981_747_7).abs() < 1e-6);
+}
+
+#[test]
+fn test_conversion_solid_angle() {
+    // A full sphere is 4π sr ≈ 41,253 square degrees
+    let sq_deg = convert(4.0 * std::f64::consts::PI, SolidAngle(Steradian(0)), SolidAngle(SquareDegree));
+    assert!((sq_deg - 41_252.961_25).abs() < 1e-4);
+    let msr = convert(1.0, SolidAngle(SquareDegree), SolidAngle(Steradian(-3)));
+    assert!((msr - 0.304_617_4).abs() < 1e-6);
+
+    // Solid angles are not plane angles
+    assert_eq!(
+        try_convert(1.0, SolidAngle(Steradian(0)), Angle(Radian(0))),
+        Err(ConversionError::IncompatibleDimensions(Dimension::SolidAngle, Dimension::Angle))
+    );
+}
+
+#[test]
+fn test_conversion_force() {
+    // 1 kgf = 9.80665 N exactly
+    let n = convert_exact(Ratio::from(1), Force(KilogramForce), Force(Newton(0)));
+    assert_eq!(n, Ok("9.80665".parse().unwrap()));
+
+    // 1 lbf ≈ 4.448222 N, and 1 kip = 1000 lbf
+    let n = convert(1.0, Force(PoundForce), Force(Newton(0)));
+    assert!((n - 4.448_221_615).abs() < 1e-9);
+    let kn = convert(1.0, Force(Kip), Force(Newton(3)));
+    assert!((kn - 4.448_221_615).abs() < 1e-9);
+
+    // 1 N = 100,000 dyn
+    let dyn_ = convert_exact(Ratio::from(1), Force(Newton(0)), Force(Dyne));
+    assert_eq!(dyn_, Ok(Ratio::from(100_000)));
+
+    // One pound-force is g₀ / (1 ft/s²) ≈ 32.174 poundals
+    let pdl = convert(1.0, Force(PoundForce), Force(Poundal));
+    assert!((pdl - 32.174_048_56).abs() < 1e-8);
+}
+
+#[test]
+fn test_conversion_torque() {
+    // 1 lbf·ft ≈ 1.355818 N·m = 12 lbf·in
+    let nm = convert(1.0, Torque(PoundForceFoot), Torque(NewtonMeter(0)));
+    assert!((nm - 1.355_818).abs() < 1e-6);
+    let lbf_in = convert_exact(Ratio::from(1), Torque(PoundForceFoot), Torque(PoundForceInch));
+    assert_eq!(lbf_in, Ok(Ratio::from(12)));
+
+    let nm = convert(1.0, Torque(KilogramForceMeter), Torque(NewtonMeter(0)));
+    assert!((nm - 9.806_65).abs() < 1e-12);
+
+    // Torque and energy are both kg·m²/s², but not interchangeable
+    assert_eq!(
+        try_convert(1.0, Energy(Joule(0)), Torque(NewtonMeter(0))),
+        Err(ConversionError::IncompatibleDimensions(Dimension::Energy, Dimension::Torque))
+    );
+    assert_eq!(
+        convert_exact(Ratio::from(1), Torque(PoundForceFoot), Energy(FootPound)),
+        Err(ConversionError::IncompatibleDimensions(Dimension::Torque, Dimension::Energy))
+    );
+}
+
+#[test]
+fn test_conversion_frequency() {
+    // 3000 rpm = 50 Hz
+    let hz = convert(3000.0, Frequency(RevolutionPerMinute), Frequency(Hertz(0)));
+    assert!((hz - 50.0).abs() < 1e-12);
+    let bpm = convert_exact(Ratio::from(2), Frequency(Hertz(0)), Frequency(BeatPerMinute));
+    assert_eq!(bpm, Ok(Ratio::from(120)));
+    let cpm = convert_exact(Ratio::from(1), Frequency(Hertz(3)), Frequency(CyclePerMinute));
+    assert_eq!(cpm, Ok(Ratio::from(60_000)));
+
+    // One hertz is 2π rad/s
+    let rad_s = convert(1.0, Frequency(Hertz(0)), Frequency(RadianPerSecond));
+    assert!((rad_s - 2.0 * std::f64::consts::PI).abs() < 1e-12);
+    let rpm = convert(std::f64::consts::PI, Frequency(RadianPerSecond), Frequency(RevolutionPerMinute));
+    assert!((rpm - 30.0).abs() < 1e-12);
+    assert_eq!(
+        convert_exact(Ratio::from(1), Frequency(RadianPerSecond), Frequency(Hertz(0))),
+        Err(ConversionError::Inexact)
+    );
+}
+
+#[test]
+fn test_conversion_wavelength() {
+    // Green light: 500 nm ≈ 599.6 THz
+    let thz = try_convert_wavelength(500.0, Length(Meter(-9)), Frequency(Hertz(12))).unwrap();
+    assert!((thz - 599.584_916).abs() < 1e-12);
+    let nm = try_convert_wavelength(thz, Frequency(Hertz(12)), Length(Meter(-9))).unwrap();
+    assert!((nm - 500.0).abs() < 1e-9);
+
+    // FM radio: 100 MHz ≈ 3 m
+    let m = try_convert_wavelength(100.0, Frequency(Hertz(6)), Length(Meter(0))).unwrap();
+    assert!((m - 2.997_924_58).abs() < 1e-12);
+
+    // Doubling the wavelength halves the frequency
+    let one = try_convert_wavelength(1.0, Length(Feet), Frequency(Hertz(6))).unwrap();
+    let two = try_convert_wavelength(2.0, Length(Feet), Frequency(Hertz(6))).unwrap();
+    assert!((one / two - 2.0).abs() < 1e-12);
+
+    // Within one dimension it is an ordinary conversion
+    let ft = try_convert_wavelength(1.0, Length(Meter(0)), Length(Feet)).unwrap();
+    assert!((ft - 3.280_839_895).abs() < 1e-9);
+
+    assert_eq!(
+        try_convert_wavelength(0.0, Length(Meter(-9)), Frequency(Hertz(0))),
+        Err(ConversionError::NonFiniteResult)
+    );
+    assert_eq!(
+        try_convert_wavelength(1.0, Mass(Gram(0)), Frequency(Hertz(0))),
+        Err(ConversionError::IncompatibleDimensions(Dimension::Mass, Dimension::Frequency))
+    );
+
+    // The ordinary conversions still refuse to mix the two
+    assert_eq!(
+        try_convert(500.0, Length(Meter(-9)), Frequency(Hertz(12))),
+        Err(ConversionError::IncompatibleDimensions(Dimension::Length, Dimension::Frequency))
+    );
+}
+
+#[test]
+fn test_conversion_fuel_economy() {
+    // 5 L/100km = 20 km/L, exactly
+    let km_l = convert_exact(Ratio::from(5), FuelEconomy(LitersPer100Km), FuelEconomy(KilometersPerLiter));
+    assert_eq!(km_l, Ok(Ratio::from(20)));
+    let l_100km = convert_exact(Ratio::from(20), FuelEconomy(KilometersPerLiter), FuelEconomy(LitersPer100Km));
+    assert_eq!(l_100km, Ok(Ratio::from(5)));
+
+    // 30 mpg (US) ≈ 7.84 L/100km, and back
+    let l_100km = convert(30.0, FuelEconomy(MilesPerGallonUs), FuelEconomy(LitersPer100Km));
+    assert!((l_100km - 7.840_486).abs() < 1e-6);
+    let mpg = convert(l_100km, FuelEconomy(LitersPer100Km), FuelEconomy(MilesPerGallonUs));
+    assert!((mpg - 30.0).abs() < 1e-12);
+
+    // Halving consumption doubles the distance
+    let a = convert(10.0, FuelEconomy(LitersPer100Km), FuelEconomy(MilesPerGallonUk));
+    let b = convert(5.0, FuelEconomy(LitersPer100Km), FuelEconomy(MilesPerGallonUk));
+    assert!((b / a - 2.0).abs() < 1e-12);
+    assert!((a - 28.248_094).abs() < 1e-6);
+
+    // An imperial gallon is larger, so the same car does more UK mpg
+    let uk = convert(1.0, FuelEconomy(MilesPerGallonUs), FuelEconomy(MilesPerGallonUk));
+    assert!((uk - 1.200_950).abs() < 1e-6);
+
+    // Using no fuel at all has no finite economy
+    assert_eq!(
+        try_convert(0.0, FuelEconomy(LitersPer100Km), FuelEconomy(KilometersPerLiter)),
+        Err(ConversionError::NonFiniteResult)
+    );
+    assert_eq!(
+        convert_exact(Ratio::ZERO, FuelEconomy(KilometersPerLiter), FuelEconomy(LitersPer100Km)),
+        Err(ConversionError::NonFiniteResult)
+    );
+}
+
+#[test]
+fn test_conversion_density() {
+    // 1 g/cm³ = 1000 kg/m³ = 1 kg/L, exactly
+    let g_cm3 = Density(MassPerVolume(Gram(0), Meter3(-2)));
+    let kg_m3 = Density(MassPerVolume(Gram(3), Meter3(0)));
+    let kg_l = Density(MassPerVolume(Gram(3), Liter(0)));
+    assert_eq!(convert_exact(Ratio::ONE, g_cm3, kg_m3), Ok(Ratio::from(1000)));
+    assert_eq!(convert_exact(Ratio::ONE, g_cm3, kg_l), Ok(Ratio::ONE));
+
+    // 1 lb/ft³ ≈ 16.018 kg/m³, built from the pound and the cubic foot
+    let kg_m3_value = convert(1.0, Density(MassPerVolume(Pound, Feet3)), kg_m3);
+    assert!((kg_m3_value - 16.018_463).abs() < 1e-6);
+
+    // Water at 4 °C weighs about 8.345 lb per US gallon
+    let lb_gal = convert(1.0, Density(SpecificGravity), Density(MassPerVolume(Pound, Gallon(UsLiquid))));
+    assert!((lb_gal - 8.345_171).abs() < 1e-6);
+    assert_eq!(convert_exact(Ratio::ONE, Density(SpecificGravity), kg_m3), Ok(Ratio::new(999_972, 1000)));
+
+    // A prefix inside a compound unit must still be named
+    assert_eq!(
+        try_convert(1.0, Density(MassPerVolume(Gram(4), Liter(0))), kg_l),
+        Err(ConversionError::UnsupportedScale(4))
+    );
+}
+
+#[test]
+fn test_conversion_concentration() {
+    let mg_l = Density(MassPerVolume(Gram(-3), Liter(0)));
+
+    // Mass concentrations are densities, so ppm and mg/L agree exactly
+    assert_eq!(convert_exact(Ratio::from(5), Concentration(PartsPerMillion), mg_l), Ok(Ratio::from(5)));
+    assert_eq!(
+        convert_exact(Ratio::ONE, Concentration(PartsPerMillion), Concentration(PartsPerBillion)),
+        Ok(Ratio::from(1000))
+    );
+    assert_eq!(
+        convert_exact(Ratio::new(9, 10), Concentration(PercentWeightPerVolume), mg_l),
+        Ok(Ratio::from(9000))
+    );
+
+    // Molarities convert among themselves without a molar mass...
+    assert_eq!(
+        convert_exact(Ratio::from(2), Concentration(MolePerLiter(-3)), Concentration(MolePerLiter(-6))),
+        Ok(Ratio::from(2000))
+    );
+
+    // ...but need one to become mass concentrations
+    assert_eq!(
+        try_convert(1.0, Concentration(MolePerLiter(-3)), mg_l),
+        Err(ConversionError::MolarMassRequired)
+    );
+    assert_eq!(
+        convert_exact(Ratio::ONE, Concentration(PartsPerMillion), Concentration(MolePerLiter(0))),
+        Err(ConversionError::MolarMassRequired)
+    );
+    // ...and the unchecked conversion gives NaN rather than assume 1 g/mol
+    assert!(convert(1.0, Concentration(MolePerLiter(-3)), mg_l).is_nan());
+    assert!(convert(1.0, Amount(Mole(0)), Mass(Gram(0))).is_nan());
+
+    // 5.5 mmol/L of glucose, 180.156 g/mol, is about 991 mg/L
+    let context = ConversionContext { molar_mass: Some(Ratio::new(180_156, 1000)), ..Default::default() };
+    let glucose = context.convert_exact(Ratio::new(55, 10), Concentration(MolePerLiter(-3)), mg_l);
+    assert_eq!(glucose, Ok(Ratio::new(990_858, 1000)));
+
+    // Neither is a speed
+    assert_eq!(
+        try_convert(1.0, Concentration(PartsPerMillion), Speed(MeterPerSecond(0))),
+        Err(ConversionError::IncompatibleDimensions(Dimension::Concentration, Dimension::Speed))
+    );
+}
+
+#[test]
+fn test_conversion_flow_rate() {
+    // 1 m³/s = 60,000 L/min, exactly
+    let l_min = convert_exact(
+        Ratio::ONE,
+        FlowRate(VolumePerTime(Meter3(0), Second(0))),
+        FlowRate(VolumePerTime(Liter(0), Minute)),
+    );
+    assert_eq!(l_min, Ok(Ratio::from(60_000)));
+
+    // 1 US gal/min = 3.785411784 L/min
+    let l_min = convert_exact(
+        Ratio::ONE,
+        FlowRate(VolumePerTime(Gallon(UsLiquid), Minute)),
+        FlowRate(VolumePerTime(Liter(0), Minute)),
+    );
+    assert_eq!(l_min, Ok(Ratio::new(3_785_411_784, 1_000_000_000)));
+
+    // 1000 CFM ≈ 0.4719 m³/s
+    let m3_s = convert(1000.0, FlowRate(VolumePerTime(Feet3, Minute)), FlowRate(VolumePerTime(Meter3(0), Second(0))));
+    assert!((m3_s - 0.471_947).abs() < 1e-6);
+
+    // 1 bbl/d ≈ 0.159 m³/d, and 42 US gal/d
+    let m3_d = convert(1.0, FlowRate(VolumePerTime(Barrel, Day)), FlowRate(VolumePerTime(Meter3(0), Day)));
+    assert!((m3_d - 0.158_987_294_928).abs() < 1e-12);
+    let gal_d = convert_exact(Ratio::ONE, FlowRate(VolumePerTime(Barrel, Day)), FlowRate(VolumePerTime(Gallon(UsLiquid), Day)));
+    assert_eq!(gal_d, Ok(Ratio::from(42)));
+
+    // A flow rate is not a volume
+    assert_eq!(
+        try_convert(1.0, FlowRate(VolumePerTime(Liter(0), Minute)), Volume(Liter(0))),
+        Err(ConversionError::IncompatibleDimensions(Dimension::FlowRate, Dimension::Volume))
+    );
+}
+
+#[test]
+fn test_conversion_mass_flow() {
+    // 3600 kg/h = 1 kg/s, exactly
+    let kg_s = convert_exact(
+        Ratio::from(3600),
+        MassFlow(MassPerTime(Gram(3), Hour)),
+        MassFlow(MassPerTime(Gram(3), Second(0))),
+    );
+    assert_eq!(kg_s, Ok(Ratio::ONE));
+
+    // 1000 lb/h ≈ 453.6 kg/h
+    let kg_h = convert(1000.0, MassFlow(MassPerTime(Pound, Hour)), MassFlow(MassPerTime(Gram(3), Hour)));
+    assert!((kg_h - 453.592_37).abs() < 1e-9);
+
+    // Prefixes inside the parts are validated
+    assert_eq!(
+        try_convert(1.0, MassFlow(MassPerTime(Gram(0), Second(4))), MassFlow(MassPerTime(Gram(0), Second(0)))),
+        Err(ConversionError::UnsupportedScale(4))
+    );
+}
+
+#[test]
+fn test_conversion_electrical() {
+    // A 2200 mAh battery holds 7920 C
+    assert_eq!(convert_exact(Ratio::from(2200), Charge(AmpereHour(-3)), Charge(Coulomb(0))), Ok(Ratio::from(7920)));
+
+    // 47 µF = 0.000047 F
+    assert_eq!(
+        convert_exact(Ratio::from(47), Capacitance(Farad(-6)), Capacitance(Farad(0))),
+        Ok(Ratio::new(47, 1_000_000))
+    );
+    assert_eq!(
+        convert_exact(Ratio::from(100), Capacitance(Farad(-9)), Capacitance(Farad(-12))),
+        Ok(Ratio::from(100_000))
+    );
+
+    // Every other family converts between its prefixes
+    assert_eq!(convert_exact(Ratio::from(3), Current(Ampere(0)), Current(Ampere(-3))), Ok(Ratio::from(3000)));
+    assert_eq!(convert_exact(Ratio::from(11), Voltage(Volt(3)), Voltage(Volt(0))), Ok(Ratio::from(11_000)));
+    assert_eq!(convert_exact(Ratio::from(4700), Resistance(Ohm(0)), Resistance(Ohm(3))), Ok(Ratio::new(47, 10)));
+    assert_eq!(convert_exact(Ratio::ONE, Conductance(Siemens(-3)), Conductance(Siemens(-6))), Ok(Ratio::from(1000)));
+    assert_eq!(convert_exact(Ratio::from(22), Inductance(Henry(-6)), Inductance(Henry(-3))), Ok(Ratio::new(22, 1000)));
+    assert_eq!(convert_exact(Ratio::ONE, MagneticFlux(Weber(0)), MagneticFlux(Weber(-3))), Ok(Ratio::from(1000)));
+
+    // 1 T = 10,000 G; Earth's field is about half a gauss
+    assert_eq!(
+        convert_exact(Ratio::ONE, MagneticFluxDensity(Tesla(0)), MagneticFluxDensity(Gauss)),
+        Ok(Ratio::from(10_000))
+    );
+    let ut = convert(0.5, MagneticFluxDensity(Gauss), MagneticFluxDensity(Tesla(-6)));
+    assert!((ut - 50.0).abs() < 1e-9);
+
+    // Each quantity is its own dimension
+    assert_eq!(
+        try_convert(1.0, Charge(Coulomb(0)), Current(Ampere(0))),
+        Err(ConversionError::IncompatibleDimensions(Dimension::Charge, Dimension::Current))
+    );
+    assert_eq!(
+        try_convert(1.0, Resistance(Ohm(0)), Conductance(Siemens(0))),
+        Err(ConversionError::IncompatibleDimensions(Dimension::Resistance, Dimension::Conductance))
+    );
+}
+
+#[test]
+fn test_conversion_photometry() {
+    // 1 fc = 10.7639 lx, and 500 lx of office lighting ≈ 46.45 fc
+    let lx = convert(1.0, Illuminance(FootCandle), Illuminance(Lux(0)));
+    assert!((lx - 10.763_910_417).abs() < 1e-9);
+    assert_eq!(
+        convert_exact(Ratio::from(500), Illuminance(Lux(0)), Illuminance(FootCandle)),
+        Ok(Ratio::new(4_645_152, 100_000))
+    );
+    assert_eq!(convert_exact(Ratio::ONE, Illuminance(Phot), Illuminance(Lux(3))), Ok(Ratio::from(10)));
+
+    // 1 fL = 3.426 cd/m², through π
+    let nits = convert(1.0, Luminance(FootLambert), Luminance(Nit));
+    assert!((nits - 3.426_259).abs() < 1e-6);
+    assert_eq!(
+        convert_exact(Ratio::ONE, Luminance(FootLambert), Luminance(Nit)),
+        Err(ConversionError::Inexact)
+    );
+
+    assert_eq!(
+        convert_exact(Ratio::from(20), LuminousIntensity(Candela(-3)), LuminousIntensity(Candela(0))),
+        Ok(Ratio::new(2, 100))
+    );
+    assert_eq!(convert_exact(Ratio::from(1600), LuminousFlux(Lumen(0)), LuminousFlux(Lumen(3))), Ok(Ratio::new(16, 10)));
+
+    // A lumen spread over an area is not the same as a lumen
+    assert_eq!(
+        try_convert(1.0, Illuminance(Lux(0)), LuminousFlux(Lumen(0))),
+        Err(ConversionError::IncompatibleDimensions(Dimension::Illuminance, Dimension::LuminousFlux))
+    );
+}
+
+#[test]
+fn test_conversion_radiation() {
+    // 1 Ci = 37 GBq, exactly
+    assert_eq!(
+        convert_exact(Ratio::ONE, Radioactivity(Curie(0)), Radioactivity(Becquerel(9))),
+        Ok(Ratio::from(37))
+    );
+    assert_eq!(
+        convert_exact(Ratio::ONE, Radioactivity(Rutherford), Radioactivity(Becquerel(3))),
+        Ok(Ratio::from(1000))
+    );
+    assert_eq!(
+        convert_exact(Ratio::from(60), Radioactivity(DisintegrationPerMinute), Radioactivity(Becquerel(0))),
+        Ok(Ratio::ONE)
+    );
+    let pci = convert(1.0, Radioactivity(Becquerel(0)), Radioactivity(Curie(-12)));
+    assert!((pci - 27.027_027).abs() < 1e-6);
+
+    // 100 rad = 1 Gy and 100 mrem = 1 mSv
+    assert_eq!(convert_exact(Ratio::from(100), AbsorbedDose(Rad(0)), AbsorbedDose(Gray(0))), Ok(Ratio::ONE));
+    assert_eq!(
+        convert_exact(Ratio::from(100), EquivalentDose(Rem(-3)), EquivalentDose(Sievert(-3))),
+        Ok(Ratio::ONE)
+    );
+
+    // Both doses are J/kg, but a gray is not a sievert
+    assert!(convert(1.0, AbsorbedDose(Gray(0)), EquivalentDose(Sievert(0))).is_nan());
+    assert!(convert(1.0, EquivalentDose(Rem(0)), AbsorbedDose(Rad(0))).is_nan());
+    assert_eq!(
+        try_convert(1.0, AbsorbedDose(Gray(0)), EquivalentDose(Sievert(0))),
+        Err(ConversionError::IncompatibleDimensions(Dimension::AbsorbedDose, Dimension::EquivalentDose))
+    );
+    assert_eq!(
+        convert_exact(Ratio::ONE, EquivalentDose(Sievert(0)), AbsorbedDose(Gray(0))),
+        Err(ConversionError::IncompatibleDimensions(Dimension::EquivalentDose, Dimension::AbsorbedDose))
+    );
+
+    // Nor is the rad of dose the radian
+    assert!(convert(1.0, AbsorbedDose(Rad(0)), Angle(Radian(0))).is_nan());
+}
+
+#[test]
+fn test_conversion_acceleration() {
+    // g₀ is exactly 9.80665 m/s² and 980.665 Gal
+    assert_eq!(
+        convert_exact(Ratio::ONE, Acceleration(StandardGravity), Acceleration(MeterPerSecond2(0))),
+        Ok(Ratio::new(980_665, 100_000))
+    );
+    assert_eq!(
+        convert_exact(Ratio::ONE, Acceleration(StandardGravity), Acceleration(Gal(0))),
+        Ok(Ratio::new(980_665, 1000))
+    );
+
+    // A gravity anomaly of 50 mGal
+    assert_eq!(
+        convert_exact(Ratio::from(50), Acceleration(Gal(-3)), Acceleration(MeterPerSecond2(0))),
+        Ok(Ratio::new(5, 10_000))
+    );
+
+    // 32.174 ft/s² is about one g
+    let g = convert(32.174, Acceleration(FootPerSecond2), Acceleration(StandardGravity));
+    assert!((g - 1.0).abs() < 1e-5);
+
+    // An acceleration is not a speed
+    assert_eq!(
+        try_convert(1.0, Acceleration(MeterPerSecond2(0)), Speed(MeterPerSecond(0))),
+        Err(ConversionError::IncompatibleDimensions(Dimension::Acceleration, Dimension::Speed))
+    );
+}
+
+#[test]
+fn test_conversion_amount() {
+    assert_eq!(convert_exact(Ratio::from(2), Amount(Mole(-3)), Amount(Mole(-6))), Ok(Ratio::from(2000)));
+    // Avogadro's number is exact
+    assert_eq!(
+        convert_exact(Ratio::ONE, Amount(Mole(0)), Amount(Particle)),
+        Ok(Ratio::from(602_214_076_000_000_000_000_000i128))
+    );
+
+    // Masses need a molar mass
+    assert_eq!(try_convert(1.0, Mass(Gram(0)), Amount(Mole(0))), Err(ConversionError::MolarMassRequired));
+    assert_eq!(
+        try_convert(1.0, Amount(Mole(0)), Temperature(Kelvin)),
+        Err(ConversionError::IncompatibleDimensions(Dimension::Amount, Dimension::Temperature))
+    );
+
+    // 12 g of glucose, 180.156 g/mol, is about 66.6 mmol
+    let glucose = molar_mass("C6H12O6").unwrap();
+    assert_eq!(glucose, Ratio::new(180_156, 1000));
+    let context = ConversionContext { molar_mass: Some(glucose), ..Default::default() };
+    let mmol = context.convert_exact(Ratio::from(12), Mass(Gram(0)), Amount(Mole(-3))).unwrap();
+    assert_eq!(mmol, Ratio::new(12_000_000, 180_156));
+    assert_eq!(context.convert_exact(Ratio::ONE, Amount(Mole(0)), Mass(Gram(-3))), Ok(Ratio::from(180_156)));
+
+    // One mole of water weighs about 18 g
+    let context = ConversionContext { molar_mass: molar_mass("H2O").ok(), ..Default::default() };
+    let particles = context.convert(18.015, Mass(Gram(0)), Amount(Particle));
+    assert!((particles / 6.022_140_76e23 - 1.0).abs() < 1e-12);
+
+    // A molar mass too large for the exact factor is refused, not a panic
+    let context = ConversionContext { molar_mass: Some(Ratio::from(i128::MAX / 2)), ..Default::default() };
+    assert_eq!(context.try_convert(1.0, Amount(Particle), Mass(Gram(0))), Err(ConversionError::Overflow));
+    assert!(context.convert(1.0, Amount(Particle), Mass(Gram(0))).is_nan());
+}
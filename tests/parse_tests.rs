@@ -0,0 +1,1084 @@
+use unit_converter::{
+    AbsorbedDoseUnit, AccelerationUnit, AmountUnit, AngleUnit, AreaUnit, CapacitanceUnit,
+    ChargeUnit, ConcentrationUnit, ConductanceUnit, CurrentUnit, DataRateUnit, DataUnit,
+    DensityUnit, Dimension, EnergyUnit, EquivalentDoseUnit, ForceUnit, FrequencyUnit,
+    FuelEconomyUnit, IlluminanceUnit, InductanceUnit, LengthUnit, LuminanceUnit, LuminousFluxUnit,
+    LuminousIntensityUnit, MagneticFluxDensityUnit, MagneticFluxUnit, MassUnit, ParseUnitError,
+    PowerUnit, PressureUnit, RadioactivityUnit, ResistanceUnit, SolidAngleUnit, SpeedUnit, TempUnit,
+    TimeUnit, TorqueUnit, Unit, VolUnit, VoltageUnit, VolumeSystem,
+};
+use unit_converter::{format_duration, molar_mass, parse_dms, parse_duration, Ratio};
+
+/// Every unit spelling the CLI accepted before parsing moved into the library.
+const CLI_ALIASES: &[&str] = &[
+    "k", "kelvin", "c", "celsius", "f", "fahrenheit",
+    "mm", "cm", "m", "km", "in", "inch", "inches", "ft", "feet",
+    "yd", "yard", "yards", "mi", "mile", "miles",
+    "mm2", "cm2", "m2", "km2", "in2", "ft2", "sqft", "yd2", "mi2",
+    "ac", "acre", "acres", "ha", "hectare", "hectares",
+    "ml", "l", "liter", "liters", "mm3", "cm3", "m3",
+    "teaspoon", "teaspoons", "tablespoon", "tablespoons", "cup", "cups",
+    "pt", "pint", "pints", "gal", "gallon", "gallons",
+    "mg", "g", "gram", "grams", "kg", "oz", "ounce", "ounces",
+    "lb", "pound", "pounds", "st", "stone", "stones",
+];
+
+#[test]
+fn test_parse_units() {
+    assert_eq!("cm".parse(), Ok(Unit::Length(LengthUnit::Meter(-2))));
+    assert_eq!("km".parse(), Ok(Unit::Length(LengthUnit::Meter(3))));
+    assert_eq!("m".parse(), Ok(Unit::Length(LengthUnit::Meter(0))));
+
+    assert_eq!("teaspoon".parse(), Ok(Unit::Volume(VolUnit::TeaSpoon(VolumeSystem::Metric))));
+    assert_eq!("gal".parse(), Ok(Unit::Volume(VolUnit::Gallon(VolumeSystem::UsLiquid))));
+
+    assert_eq!("F".parse(), Ok(Unit::Temperature(TempUnit::Fahrenheit)));
+}
+
+#[test]
+fn test_parse_invalid_units() {
+    assert!("ledsago".parse::<Unit>().is_err());
+    assert!("".parse::<Unit>().is_err());
+    assert!("cubits".parse::<Unit>().is_err());
+
+    let err = "cubits".parse::<Unit>().unwrap_err();
+    assert_eq!(err.token(), "cubits");
+    assert_eq!(err.to_string(), "Unknown unit 'cubits'");
+}
+
+#[test]
+fn test_parse_long_names() {
+    assert_eq!("kilometers".parse(), Ok(Unit::Length(LengthUnit::Meter(3))));
+    assert_eq!("square centimeters".parse(), Ok(Unit::Area(AreaUnit::Meter2(-2))));
+    assert_eq!("cubic meters".parse(), Ok(Unit::Volume(VolUnit::Meter3(0))));
+    assert_eq!("Milliliters".parse(), Ok(Unit::Volume(VolUnit::Liter(-3))));
+    assert_eq!("kilograms".parse(), Ok(Unit::Mass(MassUnit::Gram(3))));
+}
+
+#[test]
+fn test_parse_dimension_units() {
+    assert_eq!("K".parse(), Ok(TempUnit::Kelvin));
+    assert_eq!("mi".parse(), Ok(LengthUnit::Mile));
+    assert_eq!("ha".parse(), Ok(AreaUnit::Hectare));
+    assert_eq!("ml".parse(), Ok(VolUnit::Liter(-3)));
+    assert_eq!("kg".parse(), Ok(MassUnit::Gram(3)));
+
+    // A valid unit of the wrong dimension is still an error
+    assert!("kg".parse::<LengthUnit>().is_err());
+    assert!("mi".parse::<TempUnit>().is_err());
+}
+
+#[test]
+fn test_display() {
+    let km = Unit::Length(LengthUnit::Meter(3));
+    assert_eq!(km.to_string(), "kilometers");
+    assert_eq!(format!("{km:#}"), "km");
+
+    assert_eq!(AreaUnit::Meter2(-2).to_string(), "square centimeters");
+    assert_eq!(format!("{:#}", VolUnit::Liter(-3)), "mL");
+    assert_eq!(format!("{:#}", TempUnit::Celsius), "C");
+}
+
+#[test]
+fn test_round_trip_cli_aliases() {
+    for alias in CLI_ALIASES {
+        let unit: Unit = alias
+            .parse()
+            .unwrap_or_else(|err: ParseUnitError| panic!("{err}"));
+
+        let symbol = format!("{unit:#}");
+        assert_eq!(symbol.parse(), Ok(unit), "symbol '{symbol}' of '{alias}'");
+
+        let name = unit.to_string();
+        assert_eq!(name.parse(), Ok(unit), "name '{name}' of '{alias}'");
+    }
+}
+
+#[test]
+fn test_parse_ratio() {
+    use unit_converter::Ratio;
+
+    assert_eq!("1.25".parse(), Ok(Ratio::new(5, 4)));
+    assert_eq!("-3/9".parse(), Ok(Ratio::new(-1, 3)));
+    assert_eq!("2.5e-3".parse(), Ok(Ratio::new(1, 400)));
+    assert_eq!("1.5/0.5".parse(), Ok(Ratio::from(3)));
+    assert_eq!(Ratio::new(6, -4).to_string(), "-3/2");
+
+    assert!("".parse::<Ratio>().is_err());
+    assert!("1/0".parse::<Ratio>().is_err());
+    assert!("abc".parse::<Ratio>().is_err());
+}
+
+#[test]
+fn test_parse_si_prefixes() {
+    assert_eq!("µm".parse(), Ok(Unit::Length(LengthUnit::Meter(-6))));
+    assert_eq!("um".parse(), Ok(Unit::Length(LengthUnit::Meter(-6))));
+    assert_eq!("μm".parse(), Ok(Unit::Length(LengthUnit::Meter(-6))));
+    assert_eq!("nL".parse(), Ok(Unit::Volume(VolUnit::Liter(-9))));
+    assert_eq!("hm2".parse(), Ok(Unit::Area(AreaUnit::Meter2(2))));
+    assert_eq!("dam".parse(), Ok(Unit::Length(LengthUnit::Meter(1))));
+    assert_eq!("dL".parse(), Ok(Unit::Volume(VolUnit::Liter(-1))));
+    assert_eq!("Qg".parse(), Ok(Unit::Mass(MassUnit::Gram(30))));
+    assert_eq!("qg".parse(), Ok(Unit::Mass(MassUnit::Gram(-30))));
+    assert_eq!("Gm3".parse(), Ok(Unit::Volume(VolUnit::Meter3(9))));
+
+    // Case decides between milli and mega
+    assert_eq!("Mg".parse(), Ok(Unit::Mass(MassUnit::Gram(6))));
+    assert_eq!("mg".parse(), Ok(Unit::Mass(MassUnit::Gram(-3))));
+    assert_eq!("Mm".parse(), Ok(Unit::Length(LengthUnit::Meter(6))));
+    // ...so all-caps spellings are read as written, or not at all
+    assert!("MG".parse::<Unit>().is_err());
+    assert!("KM".parse::<Unit>().is_err());
+    assert_eq!("PA".parse(), Ok(Unit::Current(CurrentUnit::Ampere(15))));
+    assert_eq!("MS".parse(), Ok(Unit::Conductance(ConductanceUnit::Siemens(6))));
+    // A unit symbol in lower case leaves milli or mega in doubt
+    assert!("mhz".parse::<Unit>().is_err());
+    assert!("mn".parse::<Unit>().is_err());
+    assert_eq!("khz".parse(), Ok(Unit::Frequency(FrequencyUnit::Hertz(3))));
+    // Lower-case liters only take everyday prefixes
+    assert_eq!("ml".parse(), Ok(Unit::Volume(VolUnit::Liter(-3))));
+    assert!("fl".parse::<Unit>().is_err());
+
+    assert_eq!("micrometers".parse(), Ok(Unit::Length(LengthUnit::Meter(-6))));
+    assert_eq!("square hectometers".parse(), Ok(Unit::Area(AreaUnit::Meter2(2))));
+    assert_eq!("dekaliters".parse(), Ok(Unit::Volume(VolUnit::Liter(1))));
+    assert_eq!("Megagrams".parse(), Ok(Unit::Mass(MassUnit::Gram(6))));
+
+    assert!("xm".parse::<Unit>().is_err());
+    assert!("kilomiles".parse::<Unit>().is_err());
+}
+
+#[test]
+fn test_round_trip_si_prefixes() {
+    for scale in [-30, -27, -24, -21, -18, -15, -12, -9, -6, -3, -2, -1, 0, 1, 2, 3, 6, 9, 12, 15, 18, 21, 24, 27, 30] {
+        let units = [
+            Unit::Length(LengthUnit::Meter(scale)),
+            Unit::Area(AreaUnit::Meter2(scale)),
+            Unit::Volume(VolUnit::Liter(scale)),
+            Unit::Volume(VolUnit::Meter3(scale)),
+            Unit::Mass(MassUnit::Gram(scale)),
+        ];
+        for unit in units {
+            let symbol = format!("{unit:#}");
+            assert_eq!(symbol.parse(), Ok(unit), "symbol '{symbol}'");
+
+            let name = unit.to_string();
+            assert_eq!(name.parse(), Ok(unit), "name '{name}'");
+        }
+    }
+
+    assert_eq!(format!("{:#}", LengthUnit::Meter(-6)), "µm");
+    assert_eq!(LengthUnit::Meter(-6).to_string(), "micrometers");
+    assert_eq!(format!("{:#}", MassUnit::Gram(6)), "Mg");
+    assert_eq!(format!("{:#}", AreaUnit::Meter2(2)), "hm2");
+}
+
+#[test]
+fn test_parse_speed() {
+    assert_eq!("m/s".parse(), Ok(SpeedUnit::MeterPerSecond(0)));
+    assert_eq!("km/s".parse(), Ok(SpeedUnit::MeterPerSecond(3)));
+    assert_eq!("millimeters per second".parse(), Ok(SpeedUnit::MeterPerSecond(-3)));
+    assert_eq!("km/h".parse(), Ok(SpeedUnit::KilometerPerHour));
+    assert_eq!("KPH".parse(), Ok(SpeedUnit::KilometerPerHour));
+    assert_eq!("mph".parse(), Ok(SpeedUnit::MilePerHour));
+    assert_eq!("ft/s".parse(), Ok(SpeedUnit::FootPerSecond));
+    assert_eq!("knots".parse(), Ok(SpeedUnit::Knot));
+    assert_eq!("Mach".parse(), Ok(SpeedUnit::Mach));
+    assert_eq!("c0".parse(), Ok(SpeedUnit::SpeedOfLight));
+
+    // "c" stays celsius
+    assert_eq!("c".parse(), Ok(Unit::Temperature(TempUnit::Celsius)));
+
+    for unit in [
+        SpeedUnit::MeterPerSecond(0),
+        SpeedUnit::MeterPerSecond(3),
+        SpeedUnit::KilometerPerHour,
+        SpeedUnit::MilePerHour,
+        SpeedUnit::FootPerSecond,
+        SpeedUnit::Knot,
+        SpeedUnit::Mach,
+        SpeedUnit::SpeedOfLight,
+    ] {
+        assert_eq!(format!("{unit:#}").parse(), Ok(unit));
+        assert_eq!(unit.to_string().parse(), Ok(unit));
+    }
+}
+
+#[test]
+fn test_parse_time() {
+    assert_eq!("s".parse(), Ok(TimeUnit::Second(0)));
+    assert_eq!("ms".parse(), Ok(TimeUnit::Second(-3)));
+    assert_eq!("Ms".parse(), Ok(TimeUnit::Second(6)));
+    assert_eq!("nanoseconds".parse(), Ok(TimeUnit::Second(-9)));
+    // Picoseconds, not metric horsepower
+    assert_eq!("ps".parse(), Ok(TimeUnit::Second(-12)));
+    assert_eq!("min".parse(), Ok(TimeUnit::Minute));
+    assert_eq!("h".parse(), Ok(TimeUnit::Hour));
+    assert_eq!("days".parse(), Ok(TimeUnit::Day));
+    assert_eq!("wk".parse(), Ok(TimeUnit::Week));
+    assert_eq!("yr".parse(), Ok(TimeUnit::JulianYear));
+
+    // "m" stays meters outside of compound durations
+    assert_eq!("m".parse(), Ok(Unit::Length(LengthUnit::Meter(0))));
+
+    for unit in [
+        TimeUnit::Second(-6),
+        TimeUnit::Second(0),
+        TimeUnit::Minute,
+        TimeUnit::Hour,
+        TimeUnit::Day,
+        TimeUnit::Week,
+        TimeUnit::JulianYear,
+    ] {
+        assert_eq!(format!("{unit:#}").parse(), Ok(unit));
+        assert_eq!(unit.to_string().parse(), Ok(unit));
+    }
+}
+
+#[test]
+fn test_parse_duration() {
+    assert_eq!(parse_duration("1h30m"), Ok(5400.0));
+    assert_eq!(parse_duration("2d 4h 30m"), Ok(189_000.0));
+    assert_eq!(parse_duration("1h 30min 15.5s"), Ok(5415.5));
+    assert_eq!(parse_duration("1 week"), Ok(604_800.0));
+    assert_eq!(parse_duration("250ms"), Ok(0.25));
+    assert_eq!(parse_duration("-1h"), Ok(-3600.0));
+
+    // ISO-8601
+    assert_eq!(parse_duration("PT1H30M"), Ok(5400.0));
+    assert_eq!(parse_duration("P1W2D"), Ok(777_600.0));
+    assert_eq!(parse_duration("P1DT12H"), Ok(129_600.0));
+    assert_eq!(parse_duration("PT0,5S"), Ok(0.5));
+    assert_eq!(parse_duration("P1Y"), Ok(31_557_600.0));
+
+    assert!(parse_duration("").is_err());
+    assert!(parse_duration("90").is_err());
+    assert!(parse_duration("1h30x").is_err());
+    assert!(parse_duration("P").is_err());
+    assert!(parse_duration("PT").is_err());
+    assert!(parse_duration("P1DT").is_err());
+    // Months have no fixed length
+    assert!(parse_duration("P1M").is_err());
+    assert_eq!(
+        parse_duration("2 fortnights").unwrap_err().to_string(),
+        "Invalid duration '2 fortnights'"
+    );
+}
+
+#[test]
+fn test_format_duration() {
+    assert_eq!(format_duration(5400.0), "1h 30m");
+    assert_eq!(format_duration(189_000.0), "2d 4h 30m");
+    assert_eq!(format_duration(5415.5), "1h 30m 15.5s");
+    assert_eq!(format_duration(0.0), "0s");
+    assert_eq!(format_duration(-90.0), "-1m 30s");
+    // Float noise does not turn into "60s"
+    assert_eq!(format_duration(3599.9999999999), "1h");
+
+    for seconds in [1.0, 59.0, 3661.25, 86_400.0, 1_000_000.0, -7200.0] {
+        assert_eq!(parse_duration(&format_duration(seconds)), Ok(seconds));
+    }
+}
+
+#[test]
+fn test_parse_pressure() {
+    assert_eq!("Pa".parse(), Ok(PressureUnit::Pascal(0)));
+    assert_eq!("hPa".parse(), Ok(PressureUnit::Pascal(2)));
+    assert_eq!("kpa".parse(), Ok(PressureUnit::Pascal(3)));
+    assert_eq!("MPa".parse(), Ok(PressureUnit::Pascal(6)));
+    assert_eq!("mbar".parse(), Ok(PressureUnit::Bar(-3)));
+    assert_eq!("millibars".parse(), Ok(PressureUnit::Bar(-3)));
+    assert_eq!("atm".parse(), Ok(PressureUnit::Atmosphere));
+    assert_eq!("PSI".parse(), Ok(PressureUnit::Psi));
+    assert_eq!("psig".parse(), Ok(PressureUnit::Psig));
+    assert_eq!("mmHg".parse(), Ok(PressureUnit::MillimeterOfMercury));
+    assert_eq!("inHg".parse(), Ok(PressureUnit::InchOfMercury));
+    assert_eq!("torr".parse(), Ok(PressureUnit::Torr));
+    assert_eq!("kgf/cm2".parse(), Ok(PressureUnit::KilogramForcePerCm2));
+
+    for unit in [
+        PressureUnit::Pascal(3),
+        PressureUnit::Bar(-3),
+        PressureUnit::Atmosphere,
+        PressureUnit::Psi,
+        PressureUnit::Psig,
+        PressureUnit::MillimeterOfMercury,
+        PressureUnit::InchOfMercury,
+        PressureUnit::Torr,
+        PressureUnit::KilogramForcePerCm2,
+    ] {
+        assert_eq!(format!("{unit:#}").parse(), Ok(unit));
+        assert_eq!(unit.to_string().parse(), Ok(unit));
+    }
+}
+
+#[test]
+fn test_parse_energy() {
+    assert_eq!("J".parse(), Ok(EnergyUnit::Joule(0)));
+    assert_eq!("kJ".parse(), Ok(EnergyUnit::Joule(3)));
+    assert_eq!("kWh".parse(), Ok(EnergyUnit::WattHour(3)));
+    assert_eq!("kilowatt-hours".parse(), Ok(EnergyUnit::WattHour(3)));
+    assert_eq!("kcal".parse(), Ok(EnergyUnit::Calorie(3)));
+    assert_eq!("kilocalories".parse(), Ok(EnergyUnit::Calorie(3)));
+    assert_eq!("MeV".parse(), Ok(EnergyUnit::Electronvolt(6)));
+    assert_eq!("btu".parse(), Ok(EnergyUnit::BtuIt));
+    assert_eq!("BTU_th".parse(), Ok(EnergyUnit::BtuThermochemical));
+    assert_eq!("therm".parse(), Ok(EnergyUnit::Therm));
+    assert_eq!("ft-lbf".parse(), Ok(EnergyUnit::FootPound));
+
+    // Capitalised "Cal" is the food Calorie
+    assert_eq!("Cal".parse(), Ok(EnergyUnit::FoodCalorie));
+    assert_eq!("cal".parse(), Ok(EnergyUnit::Calorie(0)));
+
+    for unit in [
+        EnergyUnit::Joule(0),
+        EnergyUnit::WattHour(3),
+        EnergyUnit::Calorie(3),
+        EnergyUnit::Electronvolt(3),
+        EnergyUnit::CalorieIt,
+        EnergyUnit::KilocalorieIt,
+        EnergyUnit::FoodCalorie,
+        EnergyUnit::BtuIt,
+        EnergyUnit::BtuThermochemical,
+        EnergyUnit::Erg,
+        EnergyUnit::Therm,
+        EnergyUnit::FootPound,
+    ] {
+        assert_eq!(format!("{unit:#}").parse(), Ok(unit));
+        assert_eq!(unit.to_string().parse(), Ok(unit));
+    }
+}
+
+#[test]
+fn test_parse_power() {
+    assert_eq!("W".parse(), Ok(PowerUnit::Watt(0)));
+    assert_eq!("kW".parse(), Ok(PowerUnit::Watt(3)));
+    assert_eq!("megawatts".parse(), Ok(PowerUnit::Watt(6)));
+    assert_eq!("hp".parse(), Ok(PowerUnit::HorsepowerMechanical));
+    assert_eq!("PS".parse(), Ok(PowerUnit::HorsepowerMetric));
+    // ...which on its own is the petasiemens
+    assert_eq!("PS".parse(), Ok(Unit::Conductance(ConductanceUnit::Siemens(15))));
+    assert_eq!("btu/hr".parse(), Ok(PowerUnit::BtuPerHour));
+    assert_eq!("TR".parse(), Ok(PowerUnit::TonOfRefrigeration));
+    assert_eq!("dBm".parse(), Ok(PowerUnit::DecibelMilliwatt));
+    assert_eq!("dbw".parse(), Ok(PowerUnit::DecibelWatt));
+
+    // As with grams, "MW" is a megawatt and "mW" a milliwatt
+    assert_eq!("MW".parse(), Ok(PowerUnit::Watt(6)));
+    assert_eq!("mW".parse(), Ok(PowerUnit::Watt(-3)));
+
+    for unit in [
+        PowerUnit::Watt(0),
+        PowerUnit::Watt(-3),
+        PowerUnit::HorsepowerMechanical,
+        PowerUnit::HorsepowerMetric,
+        PowerUnit::HorsepowerElectrical,
+        PowerUnit::HorsepowerBoiler,
+        PowerUnit::BtuPerHour,
+        PowerUnit::TonOfRefrigeration,
+        PowerUnit::ErgPerSecond,
+        PowerUnit::DecibelMilliwatt,
+        PowerUnit::DecibelWatt,
+    ] {
+        assert_eq!(format!("{unit:#}").parse(), Ok(unit));
+        assert_eq!(unit.to_string().parse(), Ok(unit));
+    }
+}
+
+#[test]
+fn test_parse_data() {
+    assert_eq!("bit".parse(), Ok(DataUnit::Bit(0)));
+    assert_eq!("kb".parse(), Ok(DataUnit::Bit(3)));
+    assert_eq!("Mbit".parse(), Ok(DataUnit::Bit(6)));
+    assert_eq!("B".parse(), Ok(DataUnit::Byte(0)));
+    assert_eq!("kB".parse(), Ok(DataUnit::Byte(3)));
+    assert_eq!("KB".parse(), Ok(DataUnit::Byte(3)));
+    assert_eq!("GB".parse(), Ok(DataUnit::Byte(9)));
+    assert_eq!("terabytes".parse(), Ok(DataUnit::Byte(12)));
+    assert_eq!("KiB".parse(), Ok(DataUnit::BinaryByte(10)));
+    assert_eq!("MIB".parse(), Ok(DataUnit::BinaryByte(20)));
+    assert_eq!("Gibit".parse(), Ok(DataUnit::BinaryBit(30)));
+    assert_eq!("mebibytes".parse(), Ok(DataUnit::BinaryByte(20)));
+    assert_eq!("nibble".parse(), Ok(DataUnit::Nibble));
+
+    // Case tells bytes from bits, and there are no fractional bits
+    assert_eq!("MB".parse(), Ok(DataUnit::Byte(6)));
+    assert_eq!("Mb".parse(), Ok(DataUnit::Bit(6)));
+    assert!("mb".parse::<DataUnit>().is_err());
+    assert!("millibits".parse::<DataUnit>().is_err());
+
+    for unit in [
+        DataUnit::Bit(0),
+        DataUnit::Bit(3),
+        DataUnit::Byte(0),
+        DataUnit::Byte(6),
+        DataUnit::BinaryBit(10),
+        DataUnit::BinaryByte(10),
+        DataUnit::BinaryByte(80),
+        DataUnit::Nibble,
+    ] {
+        assert_eq!(format!("{unit:#}").parse(), Ok(unit));
+        assert_eq!(unit.to_string().parse(), Ok(unit));
+    }
+}
+
+#[test]
+fn test_parse_data_rate() {
+    assert_eq!("bps".parse(), Ok(DataRateUnit::BitPerSecond(0)));
+    assert_eq!("Mbps".parse(), Ok(DataRateUnit::BitPerSecond(6)));
+    assert_eq!("Mbit/s".parse(), Ok(DataRateUnit::BitPerSecond(6)));
+    assert_eq!("MB/s".parse(), Ok(DataRateUnit::BytePerSecond(6)));
+    assert_eq!("MBps".parse(), Ok(DataRateUnit::BytePerSecond(6)));
+    assert_eq!("MiB/s".parse(), Ok(DataRateUnit::BinaryBytePerSecond(20)));
+    assert_eq!("gigabits per second".parse(), Ok(DataRateUnit::BitPerSecond(9)));
+
+    for unit in [
+        DataRateUnit::BitPerSecond(0),
+        DataRateUnit::BitPerSecond(9),
+        DataRateUnit::BytePerSecond(3),
+        DataRateUnit::BinaryBitPerSecond(20),
+        DataRateUnit::BinaryBytePerSecond(20),
+    ] {
+        assert_eq!(format!("{unit:#}").parse(), Ok(unit));
+        assert_eq!(unit.to_string().parse(), Ok(unit));
+    }
+}
+
+#[test]
+fn test_parse_angle() {
+    assert_eq!("rad".parse(), Ok(AngleUnit::Radian(0)));
+    assert_eq!("mrad".parse(), Ok(AngleUnit::Radian(-3)));
+    assert_eq!("milliradians".parse(), Ok(AngleUnit::Radian(-3)));
+    assert_eq!("°".parse(), Ok(AngleUnit::Degree));
+    assert_eq!("degrees".parse(), Ok(AngleUnit::Degree));
+    assert_eq!("arcmin".parse(), Ok(AngleUnit::Arcminute));
+    assert_eq!("grad".parse(), Ok(AngleUnit::Gradian));
+    assert_eq!("rev".parse(), Ok(AngleUnit::Turn));
+    assert_eq!("streck".parse(), Ok(AngleUnit::MilSwedish));
+
+    for unit in [
+        AngleUnit::Radian(0),
+        AngleUnit::Radian(-3),
+        AngleUnit::Degree,
+        AngleUnit::Arcminute,
+        AngleUnit::Arcsecond,
+        AngleUnit::Gradian,
+        AngleUnit::Turn,
+        AngleUnit::MilNato,
+        AngleUnit::MilWarsawPact,
+        AngleUnit::MilSwedish,
+    ] {
+        assert_eq!(format!("{unit:#}").parse(), Ok(unit));
+        assert_eq!(unit.to_string().parse(), Ok(unit));
+    }
+}
+
+#[test]
+fn test_parse_solid_angle() {
+    assert_eq!("sr".parse(), Ok(SolidAngleUnit::Steradian(0)));
+    assert_eq!("msr".parse(), Ok(SolidAngleUnit::Steradian(-3)));
+    assert_eq!("deg²".parse(), Ok(SolidAngleUnit::SquareDegree));
+
+    for unit in [SolidAngleUnit::Steradian(0), SolidAngleUnit::Steradian(-3), SolidAngleUnit::SquareDegree] {
+        assert_eq!(format!("{unit:#}").parse(), Ok(unit));
+        assert_eq!(unit.to_string().parse(), Ok(unit));
+    }
+}
+
+#[test]
+fn test_parse_dms() {
+    assert_eq!(parse_dms("12°30'"), Ok(12.5));
+    assert_eq!(parse_dms("90°"), Ok(90.0));
+    assert_eq!(parse_dms("-0°30'"), Ok(-0.5));
+    assert_eq!(parse_dms("30'"), Ok(0.5));
+    assert_eq!(parse_dms("36\""), Ok(0.01));
+    assert_eq!(parse_dms("1° 30′ 36″"), Ok(1.51));
+    assert_eq!(parse_dms("1°30'36''"), Ok(1.51));
+    let degrees = parse_dms("12°30'15\"").unwrap();
+    assert!((degrees - 12.504_166_667).abs() < 1e-9);
+
+    // Compass letters give the sign
+    assert_eq!(parse_dms("0°30'W"), Ok(-0.5));
+    assert_eq!(parse_dms("0°30'N"), Ok(0.5));
+    assert!(parse_dms("-0°30'S").is_err());
+
+    assert!(parse_dms("").is_err());
+    assert!(parse_dms("12").is_err());
+    // Parts must be in order and below 60 after a larger part
+    assert!(parse_dms("30'12°").is_err());
+    assert!(parse_dms("12°75'").is_err());
+    assert!(parse_dms("12°30'15").is_err());
+    assert_eq!(parse_dms("12x").unwrap_err().to_string(), "Invalid angle '12x'");
+}
+
+#[test]
+fn test_parse_force() {
+    assert_eq!("N".parse(), Ok(ForceUnit::Newton(0)));
+    assert_eq!("MN".parse(), Ok(ForceUnit::Newton(6)));
+    assert_eq!("kilonewtons".parse(), Ok(ForceUnit::Newton(3)));
+    assert_eq!("kp".parse(), Ok(ForceUnit::KilogramForce));
+    assert_eq!("pounds-force".parse(), Ok(ForceUnit::PoundForce));
+    assert_eq!("kips".parse(), Ok(ForceUnit::Kip));
+
+    // "kN" only differs from the knot by case
+    assert_eq!("kN".parse(), Ok(ForceUnit::Newton(3)));
+    assert_eq!("kn".parse(), Ok(Unit::Speed(SpeedUnit::Knot)));
+
+    for unit in [
+        ForceUnit::Newton(0),
+        ForceUnit::Newton(3),
+        ForceUnit::Dyne,
+        ForceUnit::KilogramForce,
+        ForceUnit::PoundForce,
+        ForceUnit::Poundal,
+        ForceUnit::Kip,
+    ] {
+        assert_eq!(format!("{unit:#}").parse(), Ok(unit));
+        assert_eq!(unit.to_string().parse(), Ok(unit));
+    }
+}
+
+#[test]
+fn test_parse_torque() {
+    assert_eq!("Nm".parse(), Ok(TorqueUnit::NewtonMeter(0)));
+    assert_eq!("N*m".parse(), Ok(TorqueUnit::NewtonMeter(0)));
+    assert_eq!("kN·m".parse(), Ok(TorqueUnit::NewtonMeter(3)));
+    assert_eq!("lb-ft".parse(), Ok(TorqueUnit::PoundForceFoot));
+    assert_eq!("lbf-in".parse(), Ok(TorqueUnit::PoundForceInch));
+
+    // Lowercase "nm" stays the nanometer and "ft·lbf" the foot-pound
+    assert_eq!("nm".parse(), Ok(Unit::Length(LengthUnit::Meter(-9))));
+    assert_eq!("ft·lbf".parse(), Ok(Unit::Energy(EnergyUnit::FootPound)));
+
+    for unit in [
+        TorqueUnit::NewtonMeter(0),
+        TorqueUnit::NewtonMeter(3),
+        TorqueUnit::PoundForceFoot,
+        TorqueUnit::PoundForceInch,
+        TorqueUnit::KilogramForceMeter,
+    ] {
+        assert_eq!(format!("{unit:#}").parse(), Ok(unit));
+        assert_eq!(unit.to_string().parse(), Ok(unit));
+    }
+}
+
+#[test]
+fn test_parse_frequency() {
+    assert_eq!("Hz".parse(), Ok(FrequencyUnit::Hertz(0)));
+    assert_eq!("kHz".parse(), Ok(FrequencyUnit::Hertz(3)));
+    assert_eq!("GHz".parse(), Ok(FrequencyUnit::Hertz(9)));
+    assert_eq!("megahertz".parse(), Ok(FrequencyUnit::Hertz(6)));
+    assert_eq!("rev/min".parse(), Ok(FrequencyUnit::RevolutionPerMinute));
+    assert_eq!("BPM".parse(), Ok(FrequencyUnit::BeatPerMinute));
+
+    for unit in [
+        FrequencyUnit::Hertz(0),
+        FrequencyUnit::Hertz(6),
+        FrequencyUnit::RevolutionPerMinute,
+        FrequencyUnit::RadianPerSecond,
+        FrequencyUnit::CyclePerMinute,
+        FrequencyUnit::BeatPerMinute,
+    ] {
+        assert_eq!(format!("{unit:#}").parse(), Ok(unit));
+        assert_eq!(unit.to_string().parse(), Ok(unit));
+    }
+}
+
+#[test]
+fn test_parse_fuel_economy() {
+    assert_eq!("L/100km".parse(), Ok(FuelEconomyUnit::LitersPer100Km));
+    assert_eq!("l/100 km".parse(), Ok(FuelEconomyUnit::LitersPer100Km));
+    assert_eq!("kmpl".parse(), Ok(FuelEconomyUnit::KilometersPerLiter));
+    assert_eq!("mpg".parse(), Ok(FuelEconomyUnit::MilesPerGallonUs));
+    assert_eq!("UK mpg".parse(), Ok(FuelEconomyUnit::MilesPerGallonUk));
+
+    for unit in [
+        FuelEconomyUnit::LitersPer100Km,
+        FuelEconomyUnit::KilometersPerLiter,
+        FuelEconomyUnit::MilesPerGallonUs,
+        FuelEconomyUnit::MilesPerGallonUk,
+    ] {
+        assert_eq!(format!("{unit:#}").parse(), Ok(unit));
+        assert_eq!(unit.to_string().parse(), Ok(unit));
+    }
+}
+
+#[test]
+fn test_parse_density() {
+    assert_eq!("kg/m3".parse(), Ok(DensityUnit::MassPerVolume(MassUnit::Gram(3), VolUnit::Meter3(0))));
+    assert_eq!("kg/m³".parse(), Ok(DensityUnit::MassPerVolume(MassUnit::Gram(3), VolUnit::Meter3(0))));
+    assert_eq!("g/mL".parse(), Ok(DensityUnit::MassPerVolume(MassUnit::Gram(0), VolUnit::Liter(-3))));
+    assert_eq!("lb/gal".parse(), Ok(DensityUnit::MassPerVolume(MassUnit::Pound, VolUnit::Gallon(VolumeSystem::UsLiquid))));
+    assert_eq!("pounds per cubic foot".parse(), Ok(DensityUnit::MassPerVolume(MassUnit::Pound, VolUnit::Feet3)));
+    assert_eq!("specific gravity".parse(), Ok(DensityUnit::SpecificGravity));
+
+    for unit in [
+        DensityUnit::MassPerVolume(MassUnit::Gram(3), VolUnit::Meter3(0)),
+        DensityUnit::MassPerVolume(MassUnit::Gram(0), VolUnit::Meter3(-2)),
+        DensityUnit::MassPerVolume(MassUnit::Pound, VolUnit::Feet3),
+        DensityUnit::MassPerVolume(MassUnit::Ounce, VolUnit::Inch3),
+        DensityUnit::MassPerVolume(MassUnit::Pound, VolUnit::Gallon(VolumeSystem::UsLiquid)),
+        DensityUnit::SpecificGravity,
+    ] {
+        assert_eq!(format!("{unit:#}").parse(), Ok(unit));
+        assert_eq!(unit.to_string().parse(), Ok(unit));
+    }
+
+    // Only a mass over a volume is a density
+    assert!("m/L".parse::<Unit>().is_err());
+}
+
+#[test]
+fn test_parse_concentration() {
+    assert_eq!("mol/L".parse(), Ok(ConcentrationUnit::MolePerLiter(0)));
+    assert_eq!("mmol/l".parse(), Ok(ConcentrationUnit::MolePerLiter(-3)));
+    assert_eq!("micromoles per liter".parse(), Ok(ConcentrationUnit::MolePerLiter(-6)));
+    assert_eq!("PPM".parse(), Ok(ConcentrationUnit::PartsPerMillion));
+    assert_eq!("% w/v".parse(), Ok(ConcentrationUnit::PercentWeightPerVolume));
+
+    for unit in [
+        ConcentrationUnit::MolePerLiter(0),
+        ConcentrationUnit::MolePerLiter(-3),
+        ConcentrationUnit::PartsPerMillion,
+        ConcentrationUnit::PartsPerBillion,
+        ConcentrationUnit::PercentWeightPerVolume,
+    ] {
+        assert_eq!(format!("{unit:#}").parse(), Ok(unit));
+        assert_eq!(unit.to_string().parse(), Ok(unit));
+    }
+}
+
+#[test]
+fn test_parse_flow_rate() {
+    use unit_converter::FlowRateUnit::VolumePerTime;
+
+    assert_eq!("m3/s".parse(), Ok(VolumePerTime(VolUnit::Meter3(0), TimeUnit::Second(0))));
+    assert_eq!("m³/h".parse(), Ok(VolumePerTime(VolUnit::Meter3(0), TimeUnit::Hour)));
+    assert_eq!("L/min".parse(), Ok(VolumePerTime(VolUnit::Liter(0), TimeUnit::Minute)));
+    assert_eq!("GPM".parse(), Ok(VolumePerTime(VolUnit::Gallon(VolumeSystem::UsLiquid), TimeUnit::Minute)));
+    assert_eq!("cfm".parse(), Ok(VolumePerTime(VolUnit::Feet3, TimeUnit::Minute)));
+    assert_eq!("bbl/d".parse(), Ok(VolumePerTime(VolUnit::Barrel, TimeUnit::Day)));
+    assert_eq!("barrels per day".parse(), Ok(VolumePerTime(VolUnit::Barrel, TimeUnit::Day)));
+    assert_eq!("tsp/wk".parse(), Ok(VolumePerTime(VolUnit::TeaSpoon(VolumeSystem::Metric), TimeUnit::Week)));
+
+    for unit in [
+        VolumePerTime(VolUnit::Meter3(0), TimeUnit::Second(0)),
+        VolumePerTime(VolUnit::Liter(0), TimeUnit::Hour),
+        VolumePerTime(VolUnit::Liter(-3), TimeUnit::Second(0)),
+        VolumePerTime(VolUnit::Gallon(VolumeSystem::UsLiquid), TimeUnit::Minute),
+        VolumePerTime(VolUnit::Inch3, TimeUnit::Minute),
+        VolumePerTime(VolUnit::Barrel, TimeUnit::Day),
+        VolumePerTime(VolUnit::Cup(VolumeSystem::UsLiquid), TimeUnit::JulianYear),
+    ] {
+        assert_eq!(format!("{unit:#}").parse(), Ok(unit));
+        assert_eq!(unit.to_string().parse(), Ok(unit));
+    }
+}
+
+#[test]
+fn test_parse_mass_flow() {
+    use unit_converter::MassFlowUnit::MassPerTime;
+
+    assert_eq!("kg/s".parse(), Ok(MassPerTime(MassUnit::Gram(3), TimeUnit::Second(0))));
+    assert_eq!("kg/h".parse(), Ok(MassPerTime(MassUnit::Gram(3), TimeUnit::Hour)));
+    assert_eq!("lb/hr".parse(), Ok(MassPerTime(MassUnit::Pound, TimeUnit::Hour)));
+    assert_eq!("pounds per hour".parse(), Ok(MassPerTime(MassUnit::Pound, TimeUnit::Hour)));
+
+    for unit in [
+        MassPerTime(MassUnit::Gram(3), TimeUnit::Second(0)),
+        MassPerTime(MassUnit::Gram(-6), TimeUnit::Minute),
+        MassPerTime(MassUnit::Pound, TimeUnit::Hour),
+        MassPerTime(MassUnit::Stone, TimeUnit::Day),
+    ] {
+        assert_eq!(format!("{unit:#}").parse(), Ok(unit));
+        assert_eq!(unit.to_string().parse(), Ok(unit));
+    }
+
+    // Lengths over times are speeds, handled elsewhere
+    assert_eq!("m/s".parse(), Ok(Unit::Speed(SpeedUnit::MeterPerSecond(0))));
+}
+
+#[test]
+fn test_parse_electrical() {
+    assert_eq!("mAh".parse(), Ok(ChargeUnit::AmpereHour(-3)));
+    assert_eq!("µC".parse(), Ok(ChargeUnit::Coulomb(-6)));
+    assert_eq!("A".parse(), Ok(CurrentUnit::Ampere(0)));
+    assert_eq!("mA".parse(), Ok(CurrentUnit::Ampere(-3)));
+    assert_eq!("amps".parse(), Ok(CurrentUnit::Ampere(0)));
+    assert_eq!("kV".parse(), Ok(VoltageUnit::Volt(3)));
+    assert_eq!("kΩ".parse(), Ok(ResistanceUnit::Ohm(3)));
+    assert_eq!("\u{2126}".parse(), Ok(ResistanceUnit::Ohm(0)));
+    assert_eq!("Mohm".parse(), Ok(ResistanceUnit::Ohm(6)));
+    assert_eq!("mS".parse(), Ok(ConductanceUnit::Siemens(-3)));
+    assert_eq!("uF".parse(), Ok(CapacitanceUnit::Farad(-6)));
+    assert_eq!("picofarads".parse(), Ok(CapacitanceUnit::Farad(-12)));
+    assert_eq!("H".parse(), Ok(InductanceUnit::Henry(0)));
+    assert_eq!("µH".parse(), Ok(InductanceUnit::Henry(-6)));
+    assert_eq!("T".parse(), Ok(MagneticFluxDensityUnit::Tesla(0)));
+    assert_eq!("pT".parse(), Ok(MagneticFluxDensityUnit::Tesla(-12)));
+    assert_eq!("G".parse(), Ok(MagneticFluxDensityUnit::Gauss));
+    assert_eq!("mWb".parse(), Ok(MagneticFluxUnit::Weber(-3)));
+
+    // Lower case keeps its older meanings
+    assert_eq!("h".parse(), Ok(Unit::Time(TimeUnit::Hour)));
+    assert_eq!("ms".parse(), Ok(Unit::Time(TimeUnit::Second(-3))));
+    assert_eq!("g".parse(), Ok(Unit::Mass(MassUnit::Gram(0))));
+
+    // "C" and "F" are temperatures unless a charge or capacitance is wanted
+    assert_eq!("C".parse(), Ok(Unit::Temperature(TempUnit::Celsius)));
+    assert_eq!("F".parse(), Ok(Unit::Temperature(TempUnit::Fahrenheit)));
+    assert_eq!("C".parse(), Ok(ChargeUnit::Coulomb(0)));
+    assert_eq!("F".parse(), Ok(CapacitanceUnit::Farad(0)));
+    assert_eq!(Unit::parse_as("F", Dimension::Capacitance), Ok(Unit::Capacitance(CapacitanceUnit::Farad(0))));
+    assert_eq!(Unit::parse_as("F", Dimension::Length), Ok(Unit::Temperature(TempUnit::Fahrenheit)));
+    // ...so they print in a form that means them on its own
+    assert_eq!(format!("{:#}", ChargeUnit::Coulomb(0)), "C_charge");
+    assert_eq!(format!("{:#}", CapacitanceUnit::Farad(0)), "F_capacitance");
+
+    for unit in [
+        Unit::Charge(ChargeUnit::Coulomb(0)),
+        Unit::Charge(ChargeUnit::Coulomb(-3)),
+        Unit::Charge(ChargeUnit::AmpereHour(-3)),
+        Unit::Current(CurrentUnit::Ampere(-6)),
+        Unit::Voltage(VoltageUnit::Volt(3)),
+        Unit::Resistance(ResistanceUnit::Ohm(6)),
+        Unit::Conductance(ConductanceUnit::Siemens(0)),
+        Unit::Capacitance(CapacitanceUnit::Farad(0)),
+        Unit::Capacitance(CapacitanceUnit::Farad(-9)),
+        Unit::Inductance(InductanceUnit::Henry(0)),
+        Unit::Inductance(InductanceUnit::Henry(-12)),
+        Unit::MagneticFluxDensity(MagneticFluxDensityUnit::Tesla(-3)),
+        Unit::MagneticFluxDensity(MagneticFluxDensityUnit::Gauss),
+        Unit::MagneticFlux(MagneticFluxUnit::Weber(0)),
+    ] {
+        assert_eq!(format!("{unit:#}").parse(), Ok(unit));
+        assert_eq!(unit.to_string().parse(), Ok(unit));
+    }
+
+    // Every prefix, as some of them spell other units' aliases: MA, hA
+    for scale in [-30, -27, -24, -21, -18, -15, -12, -9, -6, -3, -2, -1, 0, 1, 2, 3, 6, 9, 12, 15, 18, 21, 24, 27, 30] {
+        for unit in [
+            Unit::Current(CurrentUnit::Ampere(scale)),
+            Unit::Inductance(InductanceUnit::Henry(scale)),
+            Unit::Conductance(ConductanceUnit::Siemens(scale)),
+        ] {
+            let symbol = format!("{unit:#}");
+            assert_eq!(symbol.parse(), Ok(unit), "symbol '{symbol}'");
+            let name = unit.to_string();
+            assert_eq!(name.parse(), Ok(unit), "name '{name}'");
+        }
+    }
+}
+
+#[test]
+fn test_parse_photometry() {
+    assert_eq!("cd".parse(), Ok(LuminousIntensityUnit::Candela(0)));
+    assert_eq!("mcd".parse(), Ok(LuminousIntensityUnit::Candela(-3)));
+    assert_eq!("lumens".parse(), Ok(LuminousFluxUnit::Lumen(0)));
+    assert_eq!("klx".parse(), Ok(IlluminanceUnit::Lux(3)));
+    assert_eq!("lux".parse(), Ok(IlluminanceUnit::Lux(0)));
+    assert_eq!("foot-candles".parse(), Ok(IlluminanceUnit::FootCandle));
+    assert_eq!("nits".parse(), Ok(LuminanceUnit::Nit));
+    assert_eq!("cd/m²".parse(), Ok(LuminanceUnit::Nit));
+    assert_eq!("ft-L".parse(), Ok(LuminanceUnit::FootLambert));
+    assert_eq!("fL".parse(), Ok(Unit::Volume(VolUnit::Liter(-15))));
+    // Case tells the phot from the picohenry
+    assert_eq!("ph".parse(), Ok(Unit::Illuminance(IlluminanceUnit::Phot)));
+    assert_eq!("pH".parse(), Ok(Unit::Inductance(InductanceUnit::Henry(-12))));
+
+    for unit in [
+        Unit::LuminousIntensity(LuminousIntensityUnit::Candela(0)),
+        Unit::LuminousFlux(LuminousFluxUnit::Lumen(3)),
+        Unit::Illuminance(IlluminanceUnit::Lux(0)),
+        Unit::Illuminance(IlluminanceUnit::FootCandle),
+        Unit::Illuminance(IlluminanceUnit::Phot),
+        Unit::Luminance(LuminanceUnit::Nit),
+        Unit::Luminance(LuminanceUnit::FootLambert),
+    ] {
+        assert_eq!(format!("{unit:#}").parse(), Ok(unit));
+        assert_eq!(unit.to_string().parse(), Ok(unit));
+    }
+}
+
+#[test]
+fn test_parse_radiation() {
+    assert_eq!("MBq".parse(), Ok(RadioactivityUnit::Becquerel(6)));
+    assert_eq!("pCi".parse(), Ok(RadioactivityUnit::Curie(-12)));
+    assert_eq!("microcuries".parse(), Ok(RadioactivityUnit::Curie(-6)));
+    assert_eq!("Rd".parse(), Ok(RadioactivityUnit::Rutherford));
+    assert_eq!("dpm".parse(), Ok(RadioactivityUnit::DisintegrationPerMinute));
+    assert_eq!("cGy".parse(), Ok(AbsorbedDoseUnit::Gray(-2)));
+    assert_eq!("µSv".parse(), Ok(EquivalentDoseUnit::Sievert(-6)));
+    assert_eq!("mrem".parse(), Ok(EquivalentDoseUnit::Rem(-3)));
+
+    // "rad" is the radian unless an absorbed dose is wanted
+    assert_eq!("rad".parse(), Ok(Unit::Angle(AngleUnit::Radian(0))));
+    assert_eq!("rad".parse(), Ok(AbsorbedDoseUnit::Rad(0)));
+    assert_eq!("mrad".parse(), Ok(AbsorbedDoseUnit::Rad(-3)));
+    assert_eq!("rads".parse(), Ok(Unit::AbsorbedDose(AbsorbedDoseUnit::Rad(0))));
+    assert_eq!("mrad_dose".parse(), Ok(Unit::AbsorbedDose(AbsorbedDoseUnit::Rad(-3))));
+    assert_eq!(format!("{:#}", AbsorbedDoseUnit::Rad(0)), "rad_dose");
+    assert_eq!(Unit::parse_as("radians", Dimension::AbsorbedDose), Ok(Unit::Angle(AngleUnit::Radian(0))));
+
+    for unit in [
+        Unit::Radioactivity(RadioactivityUnit::Becquerel(3)),
+        Unit::Radioactivity(RadioactivityUnit::Curie(-3)),
+        Unit::Radioactivity(RadioactivityUnit::Rutherford),
+        Unit::Radioactivity(RadioactivityUnit::DisintegrationPerMinute),
+        Unit::AbsorbedDose(AbsorbedDoseUnit::Gray(0)),
+        Unit::AbsorbedDose(AbsorbedDoseUnit::Rad(-3)),
+        Unit::EquivalentDose(EquivalentDoseUnit::Sievert(-3)),
+        Unit::EquivalentDose(EquivalentDoseUnit::Rem(0)),
+    ] {
+        assert_eq!(Unit::parse_as(&format!("{unit:#}"), unit.dimension()), Ok(unit));
+        assert_eq!(unit.to_string().parse(), Ok(unit));
+    }
+}
+
+#[test]
+fn test_parse_acceleration() {
+    assert_eq!("m/s²".parse(), Ok(AccelerationUnit::MeterPerSecond2(0)));
+    assert_eq!("ft/s^2".parse(), Ok(AccelerationUnit::FootPerSecond2));
+    assert_eq!("g₀".parse(), Ok(AccelerationUnit::StandardGravity));
+    assert_eq!("mGal".parse(), Ok(AccelerationUnit::Gal(-3)));
+    assert_eq!("milligals".parse(), Ok(AccelerationUnit::Gal(-3)));
+
+    // Case tells the gal from the gallon
+    assert_eq!("Gal".parse(), Ok(Unit::Acceleration(AccelerationUnit::Gal(0))));
+    assert_eq!("gal".parse(), Ok(Unit::Volume(VolUnit::Gallon(VolumeSystem::UsLiquid))));
+    assert!("mgal".parse::<Unit>().is_err());
+
+    for unit in [
+        AccelerationUnit::MeterPerSecond2(0),
+        AccelerationUnit::MeterPerSecond2(-3),
+        AccelerationUnit::FootPerSecond2,
+        AccelerationUnit::StandardGravity,
+        AccelerationUnit::Gal(0),
+        AccelerationUnit::Gal(-6),
+    ] {
+        assert_eq!(format!("{unit:#}").parse(), Ok(unit));
+        assert_eq!(unit.to_string().parse(), Ok(unit));
+    }
+}
+
+#[test]
+fn test_parse_amount() {
+    assert_eq!("mol".parse(), Ok(AmountUnit::Mole(0)));
+    assert_eq!("mmol".parse(), Ok(AmountUnit::Mole(-3)));
+    assert_eq!("µmol".parse(), Ok(AmountUnit::Mole(-6)));
+    assert_eq!("kilomoles".parse(), Ok(AmountUnit::Mole(3)));
+    assert_eq!("molecules".parse(), Ok(AmountUnit::Particle));
+    // Molarities still parse as concentrations
+    assert_eq!("mmol/L".parse(), Ok(Unit::Concentration(ConcentrationUnit::MolePerLiter(-3))));
+
+    for unit in [Unit::Amount(AmountUnit::Mole(-3)), Unit::Amount(AmountUnit::Particle)] {
+        assert_eq!(format!("{unit:#}").parse(), Ok(unit));
+        assert_eq!(unit.to_string().parse(), Ok(unit));
+    }
+}
+
+#[test]
+fn test_parse_molar_mass() {
+    assert_eq!(molar_mass("H2O"), Ok(Ratio::new(18_015, 1000)));
+    assert_eq!(molar_mass("NaCl"), Ok(Ratio::new(58_440, 1000)));
+    // Co is cobalt, CO carbon monoxide
+    assert_eq!(molar_mass("Co"), Ok(Ratio::new(58_933, 1000)));
+    assert_eq!(molar_mass("CO"), Ok(Ratio::new(28_010, 1000)));
+    // Groups, brackets and hydrates
+    assert_eq!(molar_mass("Ca(OH)2"), Ok(Ratio::new(74_092, 1000)));
+    assert_eq!(molar_mass("K4[Fe(CN)6]"), Ok(Ratio::new(368_345, 1000)));
+    assert_eq!(molar_mass("CuSO4·5H2O"), Ok(Ratio::new(249_677, 1000)));
+    assert_eq!(molar_mass("CuSO4*5H2O"), molar_mass("CuSO4.5H2O"));
+
+    assert!(molar_mass("").is_err());
+    assert!(molar_mass("Xx").is_err());
+    assert!(molar_mass("h2o").is_err());
+    assert!(molar_mass("H0").is_err());
+    assert!(molar_mass("Ca(OH2").is_err());
+    assert!(molar_mass("CaOH)2").is_err());
+    assert!(molar_mass("()").is_err());
+    assert_eq!(molar_mass("C6H12O6x").unwrap_err().to_string(), "Invalid chemical formula 'C6H12O6x'");
+}
+
+#[test]
+fn test_parse_volume_systems() {
+    use VolumeSystem::*;
+
+    assert_eq!("uk gal".parse(), Ok(VolUnit::Gallon(Imperial)));
+    assert_eq!("Imperial gallons".parse(), Ok(VolUnit::Gallon(Imperial)));
+    assert_eq!("gal_UK".parse(), Ok(VolUnit::Gallon(Imperial)));
+    assert_eq!("us pt".parse(), Ok(VolUnit::Pint(UsLiquid)));
+    assert_eq!("US dry pints".parse(), Ok(VolUnit::Pint(UsDry)));
+    assert_eq!("fl oz".parse(), Ok(VolUnit::FluidOunce(UsLiquid)));
+    assert_eq!("imp fl oz".parse(), Ok(VolUnit::FluidOunce(Imperial)));
+    assert_eq!("au tbsp".parse(), Ok(VolUnit::TableSpoon(Australian)));
+    assert_eq!("metric cup".parse(), Ok(VolUnit::Cup(Metric)));
+    assert_eq!("bu".parse(), Ok(VolUnit::Bushel(UsDry)));
+    assert_eq!("qt".parse(), Ok(VolUnit::Quart(UsLiquid)));
+    // Qualified densities and flow rates
+    assert_eq!("lb/uk gal".parse(), Ok(DensityUnit::MassPerVolume(MassUnit::Pound, VolUnit::Gallon(Imperial))));
+
+    // Only units that exist in the system
+    assert!("metric gal".parse::<Unit>().is_err());
+    assert!("au cup".parse::<Unit>().is_err());
+    assert!("us dry fl oz".parse::<Unit>().is_err());
+    assert!("uk liter".parse::<Unit>().is_err());
+
+    for system in [UsLiquid, UsDry, Imperial, Metric, Australian] {
+        for volume in [
+            VolUnit::TeaSpoon(system),
+            VolUnit::TableSpoon(system),
+            VolUnit::FluidOunce(system),
+            VolUnit::Gill(system),
+            VolUnit::Cup(system),
+            VolUnit::Pint(system),
+            VolUnit::Quart(system),
+            VolUnit::Gallon(system),
+            VolUnit::Peck(system),
+            VolUnit::Bushel(system),
+        ] {
+            let unit = Unit::Volume(volume);
+            if let Ok(parsed) = unit.to_string().parse::<Unit>() {
+                assert_eq!(parsed, unit);
+                assert_eq!(format!("{unit:#}").parse(), Ok(unit));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_parse_tons() {
+    assert_eq!("cwt".parse(), Ok(MassUnit::ShortHundredweight));
+    assert_eq!("uk cwt".parse(), Ok(MassUnit::LongHundredweight));
+    assert_eq!("ton".parse(), Ok(MassUnit::ShortTon));
+    assert_eq!("UK ton".parse(), Ok(MassUnit::LongTon));
+    assert_eq!("metric ton".parse(), Ok(MassUnit::Tonne));
+    assert_eq!("t".parse(), Ok(MassUnit::Tonne));
+    // Case tells the tesla from the tonne
+    assert_eq!("T".parse(), Ok(Unit::MagneticFluxDensity(MagneticFluxDensityUnit::Tesla(0))));
+
+    for mass in [
+        MassUnit::ShortHundredweight,
+        MassUnit::LongHundredweight,
+        MassUnit::ShortTon,
+        MassUnit::LongTon,
+        MassUnit::Tonne,
+    ] {
+        let unit = Unit::Mass(mass);
+        assert_eq!(format!("{unit:#}").parse(), Ok(unit));
+        assert_eq!(unit.to_string().parse(), Ok(unit));
+    }
+}
+
+#[test]
+fn test_parse_mass_systems() {
+    // "ozt" is the troy ounce, "oz" always the avoirdupois one
+    assert_eq!("ozt".parse(), Ok(MassUnit::TroyOunce));
+    assert_eq!("troy oz".parse(), Ok(MassUnit::TroyOunce));
+    assert_eq!("oz".parse(), Ok(MassUnit::Ounce));
+    assert_eq!("lbt".parse(), Ok(MassUnit::TroyPound));
+    assert_eq!("dwt".parse(), Ok(MassUnit::Pennyweight));
+    assert_eq!("gr".parse(), Ok(MassUnit::Grain));
+    assert_eq!("ct".parse(), Ok(MassUnit::Carat));
+    assert_eq!("dr".parse(), Ok(MassUnit::Dram));
+    assert_eq!("ʒ".parse(), Ok(MassUnit::ApothecaryDram));
+    assert_eq!("℈".parse(), Ok(MassUnit::Scruple));
+    assert_eq!("slugs".parse(), Ok(MassUnit::Slug));
+    // Case tells the centitesla from the carat
+    assert_eq!("cT".parse(), Ok(Unit::MagneticFluxDensity(MagneticFluxDensityUnit::Tesla(-2))));
+
+    for mass in [
+        MassUnit::Dram,
+        MassUnit::Grain,
+        MassUnit::TroyOunce,
+        MassUnit::TroyPound,
+        MassUnit::Pennyweight,
+        MassUnit::ApothecaryDram,
+        MassUnit::Scruple,
+        MassUnit::Carat,
+        MassUnit::Slug,
+    ] {
+        let unit = Unit::Mass(mass);
+        assert_eq!(format!("{unit:#}").parse(), Ok(unit));
+        assert_eq!(unit.to_string().parse(), Ok(unit));
+    }
+}
+
+#[test]
+fn test_parse_length_units() {
+    assert_eq!("nmi".parse(), Ok(LengthUnit::NauticalMile));
+    assert_eq!("fathoms".parse(), Ok(LengthUnit::Fathom));
+    assert_eq!("thou".parse(), Ok(LengthUnit::Mil));
+    assert_eq!("micron".parse(), Ok(LengthUnit::Meter(-6)));
+    assert_eq!("ft_US".parse(), Ok(LengthUnit::SurveyFoot));
+    assert_eq!("US survey feet".parse(), Ok(LengthUnit::SurveyFoot));
+    assert_eq!("survey mile".parse(), Ok(LengthUnit::SurveyMile));
+    assert_eq!("Å".parse(), Ok(LengthUnit::Angstrom));
+    // The angstrom sign
+    assert_eq!("\u{212b}".parse(), Ok(LengthUnit::Angstrom));
+    assert_eq!("AU".parse(), Ok(LengthUnit::AstronomicalUnit));
+    assert_eq!("ly".parse(), Ok(LengthUnit::LightYear));
+
+    // Case tells the rod from the rutherford, and the parsec and chain from
+    // the picocoulomb and centihenry
+    assert_eq!("rd".parse(), Ok(Unit::Length(LengthUnit::Rod)));
+    assert_eq!("Rd".parse(), Ok(Unit::Radioactivity(RadioactivityUnit::Rutherford)));
+    assert_eq!("pc".parse(), Ok(Unit::Length(LengthUnit::Parsec)));
+    assert_eq!("pC".parse(), Ok(Unit::Charge(ChargeUnit::Coulomb(-12))));
+    assert_eq!("ch".parse(), Ok(Unit::Length(LengthUnit::Chain)));
+    assert_eq!("cH".parse(), Ok(Unit::Inductance(InductanceUnit::Henry(-2))));
+
+    for length in [
+        LengthUnit::Mil,
+        LengthUnit::NauticalMile,
+        LengthUnit::Fathom,
+        LengthUnit::Chain,
+        LengthUnit::Link,
+        LengthUnit::Rod,
+        LengthUnit::Furlong,
+        LengthUnit::League,
+        LengthUnit::SurveyFoot,
+        LengthUnit::SurveyMile,
+        LengthUnit::Angstrom,
+        LengthUnit::AstronomicalUnit,
+        LengthUnit::LightYear,
+        LengthUnit::Parsec,
+    ] {
+        let unit = Unit::Length(length);
+        assert_eq!(format!("{unit:#}").parse(), Ok(unit));
+        assert_eq!(unit.to_string().parse(), Ok(unit));
+    }
+}
+
+#[test]
+fn test_parse_typographic_units() {
+    assert_eq!("points".parse(), Ok(LengthUnit::Point));
+    assert_eq!("pt_DTP".parse(), Ok(LengthUnit::Point));
+    assert_eq!("picas".parse(), Ok(LengthUnit::Pica));
+    assert_eq!("Didot points".parse(), Ok(LengthUnit::DidotPoint));
+    assert_eq!("dd".parse(), Ok(LengthUnit::DidotPoint));
+    assert_eq!("ciceros".parse(), Ok(LengthUnit::Cicero));
+    assert_eq!("twips".parse(), Ok(LengthUnit::Twip));
+    assert_eq!("em".parse(), Ok(LengthUnit::Em));
+    assert_eq!("px".parse(), Ok(LengthUnit::Pixel));
+
+    // "pt" alone is a pint, and a point where a length is expected
+    assert_eq!("pt".parse(), Ok(Unit::Volume(VolUnit::Pint(VolumeSystem::UsLiquid))));
+    assert_eq!(Unit::parse_as("pt", Dimension::Length), Ok(Unit::Length(LengthUnit::Point)));
+    // Case tells the exameter from the em
+    assert_eq!("Em".parse(), Ok(Unit::Length(LengthUnit::Meter(18))));
+
+    for length in [
+        LengthUnit::Point,
+        LengthUnit::Pica,
+        LengthUnit::DidotPoint,
+        LengthUnit::Cicero,
+        LengthUnit::Twip,
+        LengthUnit::Em,
+        LengthUnit::Pixel,
+    ] {
+        let unit = Unit::Length(length);
+        assert_eq!(format!("{unit:#}").parse(), Ok(unit));
+        assert_eq!(unit.to_string().parse(), Ok(unit));
+    }
+}